@@ -7,7 +7,7 @@ use blst::min_pk as bls;
 use blst::BLST_ERROR;
 
 // domain string, must match what is used in signing. This one should be good for beacon chain
-const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+pub(crate) const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
 
 pub const BLS_SIGNATURE_BYTES_LEN: usize = 96;
 pub const BLS_PUBLIC_KEY_BYTES_LEN: usize = 48;
@@ -54,11 +54,25 @@ impl BlsPublicKey {
     pub fn to_bytes(&self) -> &[u8] {
         &self.0
     }
+
+    /// Wrap a compressed public key, failing if it is not `BLS_PUBLIC_KEY_BYTES_LEN` bytes long
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BlsError> {
+        Vector::try_from(bytes.to_vec())
+            .map(Self)
+            .map_err(|e| BlsError::Other(alloc::format!("{e:?}")))
+    }
 }
 impl BlsSignature {
     pub fn to_bytes(&self) -> &[u8] {
         &self.0
     }
+
+    /// Wrap a compressed signature, failing if it is not `BLS_SIGNATURE_BYTES_LEN` bytes long
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BlsError> {
+        Vector::try_from(bytes.to_vec())
+            .map(Self)
+            .map_err(|e| BlsError::Other(alloc::format!("{e:?}")))
+    }
 }
 #[cfg(feature = "serde")]
 impl TryFrom<String> for BlsSignature {
@@ -0,0 +1,195 @@
+use crate::{Epoch, Slot, VerificationError};
+
+pub fn compute_epoch_at_slot<const SLOTS_PER_EPOCH: usize>(slot: Slot) -> Epoch {
+    slot / SLOTS_PER_EPOCH as u64
+}
+
+pub fn compute_start_slot_at_epoch<const SLOTS_PER_EPOCH: usize>(epoch: Epoch) -> Slot {
+    epoch * SLOTS_PER_EPOCH as u64
+}
+
+pub fn compute_sync_committee_period<const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize>(
+    epoch: Epoch,
+) -> u64 {
+    epoch / EPOCHS_PER_SYNC_COMMITTEE_PERIOD as u64
+}
+
+pub fn compute_sync_committee_period_at_slot<
+    const SLOTS_PER_EPOCH: usize,
+    const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize,
+>(
+    slot: Slot,
+) -> u64 {
+    let epoch = compute_epoch_at_slot::<SLOTS_PER_EPOCH>(slot);
+    compute_sync_committee_period::<EPOCHS_PER_SYNC_COMMITTEE_PERIOD>(epoch)
+}
+
+/// A source of the current unix time in seconds.
+///
+/// Any `Fn() -> u64` is a time source, so a fixed or manually advanced time can be injected in tests.
+pub trait TimeSource {
+    fn unix_time(&self) -> u64;
+}
+
+impl<F: Fn() -> u64> TimeSource for F {
+    fn unix_time(&self) -> u64 {
+        self()
+    }
+}
+
+/// The system clock
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemTimeSource;
+
+#[cfg(feature = "std")]
+impl TimeSource for SystemTimeSource {
+    fn unix_time(&self) -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default()
+    }
+}
+
+/// Reasons a `SlotClock` cannot be created
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClockError {
+    /// Slots of zero seconds do not divide time into slots
+    ZeroSecondsPerSlot,
+}
+
+/// Maps the time given by a `TimeSource` to slots, epochs and sync committee periods of a chain
+/// starting at `genesis_time`.
+#[derive(Clone, Debug)]
+pub struct SlotClock<
+    T: TimeSource,
+    const SLOTS_PER_EPOCH: usize,
+    const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize,
+> {
+    genesis_time: u64,
+    seconds_per_slot: u64,
+    time_source: T,
+}
+
+impl<
+        T: TimeSource,
+        const SLOTS_PER_EPOCH: usize,
+        const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize,
+    > SlotClock<T, SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>
+{
+    pub fn new(
+        genesis_time: u64,
+        seconds_per_slot: u64,
+        time_source: T,
+    ) -> Result<Self, ClockError> {
+        if seconds_per_slot == 0 {
+            return Err(ClockError::ZeroSecondsPerSlot);
+        }
+        Ok(Self {
+            genesis_time,
+            seconds_per_slot,
+            time_source,
+        })
+    }
+
+    pub fn genesis_time(&self) -> u64 {
+        self.genesis_time
+    }
+
+    pub fn seconds_per_slot(&self) -> u64 {
+        self.seconds_per_slot
+    }
+
+    /// Slot at unix time `time`. Times before genesis map to the genesis slot.
+    pub fn slot_at_time(&self, time: u64) -> Slot {
+        time.saturating_sub(self.genesis_time) / self.seconds_per_slot
+    }
+
+    /// Unix time at which `slot` starts, `None` if it is too far in the future to fit in a `u64`
+    pub fn start_time_of_slot(&self, slot: Slot) -> Option<u64> {
+        slot.checked_mul(self.seconds_per_slot)?
+            .checked_add(self.genesis_time)
+    }
+
+    pub fn current_slot(&self) -> Slot {
+        self.slot_at_time(self.time_source.unix_time())
+    }
+
+    pub fn current_epoch(&self) -> Epoch {
+        compute_epoch_at_slot::<SLOTS_PER_EPOCH>(self.current_slot())
+    }
+
+    pub fn current_sync_committee_period(&self) -> u64 {
+        compute_sync_committee_period_at_slot::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>(
+            self.current_slot(),
+        )
+    }
+
+    /// Reject a `signature_slot` that has not started yet, or whose start time overflows
+    pub fn verify_signature_slot(&self, signature_slot: Slot) -> Result<(), VerificationError> {
+        let current_slot = self.current_slot();
+        if signature_slot > current_slot || self.start_time_of_slot(signature_slot).is_none() {
+            return Err(VerificationError::FutureSignatureSlot {
+                signature_slot,
+                current_slot,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::presets::minimal;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_slot_clock() {
+        let now = Cell::new(1_000);
+        let clock = SlotClock::<
+            _,
+            { minimal::SLOTS_PER_EPOCH },
+            { minimal::EPOCHS_PER_SYNC_COMMITTEE_PERIOD },
+        >::new(1_000, 6, || now.get())
+        .unwrap();
+        assert_eq!(clock.current_slot(), 0);
+
+        now.set(1_000 + 6 * 64 + 5);
+        assert_eq!(clock.current_slot(), 64);
+        assert_eq!(clock.current_epoch(), 8);
+        assert_eq!(clock.current_sync_committee_period(), 1);
+        assert_eq!(clock.start_time_of_slot(64), Some(1_000 + 6 * 64));
+        assert_eq!(clock.start_time_of_slot(u64::MAX / 6), None);
+
+        assert!(clock.verify_signature_slot(64).is_ok());
+        assert!(matches!(
+            clock.verify_signature_slot(65),
+            Err(VerificationError::FutureSignatureSlot {
+                signature_slot: 65,
+                current_slot: 64
+            })
+        ));
+        assert!(matches!(
+            clock.verify_signature_slot(u64::MAX),
+            Err(VerificationError::FutureSignatureSlot {
+                signature_slot: u64::MAX,
+                current_slot: 64
+            })
+        ));
+
+        // before genesis
+        now.set(0);
+        assert_eq!(clock.current_slot(), 0);
+
+        assert!(matches!(
+            SlotClock::<
+                _,
+                { minimal::SLOTS_PER_EPOCH },
+                { minimal::EPOCHS_PER_SYNC_COMMITTEE_PERIOD },
+            >::new(1_000, 0, || now.get()),
+            Err(ClockError::ZeroSecondsPerSlot)
+        ));
+    }
+}
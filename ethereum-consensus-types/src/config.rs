@@ -0,0 +1,273 @@
+//! Runtime configuration of a chain, as opposed to the compile time preset.
+//!
+//! See https://github.com/ethereum/consensus-specs/tree/dev/configs
+use crate::clock::{ClockError, SlotClock, TimeSource};
+use crate::{Epoch, ForkData, ForkSchedule, Root, Version};
+use alloc::{string::String, vec::Vec};
+
+/// Epoch of a fork that is not scheduled
+pub const FAR_FUTURE_EPOCH: Epoch = u64::MAX;
+
+/// The values of a consensus `config.yaml` needed to follow a chain with a light client.
+///
+/// Only the preset, genesis and slot timing values are required. Unknown keys are ignored, forks
+/// missing from older configs are left unscheduled and the remaining values default to mainnet,
+/// so configs of devnets and shadow forks load as they are.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
+pub struct ChainConfig {
+    /// Name of the preset the chain is run with, e.g. `mainnet`
+    pub preset_base: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub config_name: String,
+
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "mainnet_min_genesis_active_validator_count")
+    )]
+    pub min_genesis_active_validator_count: u64,
+    pub min_genesis_time: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::as_hex"))]
+    pub genesis_fork_version: Version,
+    pub genesis_delay: u64,
+
+    #[cfg_attr(feature = "serde", serde(with = "crate::as_hex", default))]
+    pub altair_fork_version: Version,
+    #[cfg_attr(feature = "serde", serde(default = "far_future_epoch"))]
+    pub altair_fork_epoch: Epoch,
+    #[cfg_attr(feature = "serde", serde(with = "crate::as_hex", default))]
+    pub bellatrix_fork_version: Version,
+    #[cfg_attr(feature = "serde", serde(default = "far_future_epoch"))]
+    pub bellatrix_fork_epoch: Epoch,
+    #[cfg_attr(feature = "serde", serde(with = "crate::as_hex", default))]
+    pub capella_fork_version: Version,
+    #[cfg_attr(feature = "serde", serde(default = "far_future_epoch"))]
+    pub capella_fork_epoch: Epoch,
+    #[cfg_attr(feature = "serde", serde(with = "crate::as_hex", default))]
+    pub deneb_fork_version: Version,
+    #[cfg_attr(feature = "serde", serde(default = "far_future_epoch"))]
+    pub deneb_fork_epoch: Epoch,
+    #[cfg_attr(feature = "serde", serde(with = "crate::as_hex", default))]
+    pub electra_fork_version: Version,
+    #[cfg_attr(feature = "serde", serde(default = "far_future_epoch"))]
+    pub electra_fork_epoch: Epoch,
+    #[cfg_attr(feature = "serde", serde(with = "crate::as_hex", default))]
+    pub fulu_fork_version: Version,
+    #[cfg_attr(feature = "serde", serde(default = "far_future_epoch"))]
+    pub fulu_fork_epoch: Epoch,
+
+    pub seconds_per_slot: u64,
+    #[cfg_attr(feature = "serde", serde(default = "mainnet_seconds_per_eth1_block"))]
+    pub seconds_per_eth1_block: u64,
+    pub min_validator_withdrawability_delay: Epoch,
+    #[cfg_attr(feature = "serde", serde(default = "mainnet_shard_committee_period"))]
+    pub shard_committee_period: Epoch,
+    #[cfg_attr(feature = "serde", serde(default = "mainnet_eth1_follow_distance"))]
+    pub eth1_follow_distance: u64,
+
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "mainnet_min_per_epoch_churn_limit")
+    )]
+    pub min_per_epoch_churn_limit: u64,
+    #[cfg_attr(feature = "serde", serde(default = "mainnet_churn_limit_quotient"))]
+    pub churn_limit_quotient: u64,
+}
+
+#[cfg(feature = "serde")]
+fn far_future_epoch() -> Epoch {
+    FAR_FUTURE_EPOCH
+}
+
+#[cfg(feature = "serde")]
+fn mainnet_min_genesis_active_validator_count() -> u64 {
+    16384
+}
+
+#[cfg(feature = "serde")]
+fn mainnet_seconds_per_eth1_block() -> u64 {
+    14
+}
+
+#[cfg(feature = "serde")]
+fn mainnet_shard_committee_period() -> Epoch {
+    256
+}
+
+#[cfg(feature = "serde")]
+fn mainnet_eth1_follow_distance() -> u64 {
+    2048
+}
+
+#[cfg(feature = "serde")]
+fn mainnet_min_per_epoch_churn_limit() -> u64 {
+    4
+}
+
+#[cfg(feature = "serde")]
+fn mainnet_churn_limit_quotient() -> u64 {
+    65536
+}
+
+impl ChainConfig {
+    /// Parse the contents of a consensus `config.yaml`
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(yaml: &str) -> Result<Self, serde_yaml::Error> {
+        serde_yaml::from_str(yaml)
+    }
+
+    /// `(activation_epoch, fork_version)` of the genesis fork and every scheduled fork
+    pub fn forks(&self) -> Vec<(Epoch, Version)> {
+        [
+            (0, self.genesis_fork_version),
+            (self.altair_fork_epoch, self.altair_fork_version),
+            (self.bellatrix_fork_epoch, self.bellatrix_fork_version),
+            (self.capella_fork_epoch, self.capella_fork_version),
+            (self.deneb_fork_epoch, self.deneb_fork_version),
+            (self.electra_fork_epoch, self.electra_fork_version),
+            (self.fulu_fork_epoch, self.fulu_fork_version),
+        ]
+        .into_iter()
+        .filter(|(epoch, _)| *epoch != FAR_FUTURE_EPOCH)
+        .collect()
+    }
+
+    /// The fork schedule of the chain with the given `genesis_validators_root`, which is taken from
+    /// the genesis state rather than the config
+    pub fn fork_schedule(&self, genesis_validators_root: Root) -> ForkSchedule {
+        ForkSchedule::new(
+            genesis_validators_root,
+            self.forks(),
+            self.capella_fork_epoch,
+            self.deneb_fork_epoch,
+        )
+    }
+
+    /// The `ForkData` of the fork active at `epoch`
+    pub fn fork_data_at_epoch(&self, epoch: Epoch, genesis_validators_root: Root) -> ForkData {
+        ForkData {
+            fork_version: self
+                .fork_schedule(genesis_validators_root)
+                .fork_version_at_epoch(epoch),
+            genesis_validators_root,
+        }
+    }
+
+    /// A clock of the chain started at `genesis_time`, which is taken from the genesis state
+    /// rather than the config. Fails if the config has zero `seconds_per_slot`.
+    pub fn slot_clock<
+        T: TimeSource,
+        const SLOTS_PER_EPOCH: usize,
+        const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize,
+    >(
+        &self,
+        genesis_time: u64,
+        time_source: T,
+    ) -> Result<SlotClock<T, SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>, ClockError> {
+        SlotClock::new(genesis_time, self.seconds_per_slot, time_source)
+    }
+}
+
+#[cfg(all(test, feature = "yaml"))]
+mod test {
+    use super::*;
+
+    // excerpt of the mainnet config.yaml
+    const MAINNET_CONFIG: &str = r#"
+PRESET_BASE: 'mainnet'
+CONFIG_NAME: 'mainnet'
+TERMINAL_TOTAL_DIFFICULTY: 58750000000000000000000
+MIN_GENESIS_ACTIVE_VALIDATOR_COUNT: 16384
+MIN_GENESIS_TIME: 1606824000
+GENESIS_FORK_VERSION: 0x00000000
+GENESIS_DELAY: 604800
+ALTAIR_FORK_VERSION: 0x01000000
+ALTAIR_FORK_EPOCH: 74240
+BELLATRIX_FORK_VERSION: 0x02000000
+BELLATRIX_FORK_EPOCH: 144896
+CAPELLA_FORK_VERSION: 0x03000000
+CAPELLA_FORK_EPOCH: 194048
+DENEB_FORK_VERSION: 0x04000000
+DENEB_FORK_EPOCH: 269568
+ELECTRA_FORK_VERSION: 0x05000000
+ELECTRA_FORK_EPOCH: 364032
+SECONDS_PER_SLOT: 12
+SECONDS_PER_ETH1_BLOCK: 14
+MIN_VALIDATOR_WITHDRAWABILITY_DELAY: 256
+SHARD_COMMITTEE_PERIOD: 256
+ETH1_FOLLOW_DISTANCE: 2048
+MIN_PER_EPOCH_CHURN_LIMIT: 4
+CHURN_LIMIT_QUOTIENT: 65536
+DEPOSIT_CONTRACT_ADDRESS: 0x00000000219ab540356cBB839Cbe05303d7705Fa
+"#;
+
+    #[test]
+    fn test_chain_config_from_yaml() {
+        let config = ChainConfig::from_yaml(MAINNET_CONFIG).unwrap();
+        assert_eq!(config.preset_base, "mainnet");
+        assert_eq!(config.seconds_per_slot, 12);
+        assert_eq!(config.altair_fork_version, [1, 0, 0, 0]);
+        assert_eq!(config.fulu_fork_epoch, FAR_FUTURE_EPOCH);
+        assert_eq!(config.forks().len(), 6);
+
+        let fork_data = config.fork_data_at_epoch(194048, Root::default());
+        assert_eq!(fork_data.fork_version, [3, 0, 0, 0]);
+        let schedule = config.fork_schedule(Root::default());
+        assert_eq!(schedule.fork_version_at_epoch(194047), [2, 0, 0, 0]);
+        assert_eq!(schedule.fork_version_at_epoch(u64::MAX), [5, 0, 0, 0]);
+
+        let genesis_time: u64 = 1606824023;
+        let clock = config.slot_clock::<_, 32, 256>(genesis_time, move || genesis_time + 12 * 32);
+        assert_eq!(clock.unwrap().current_epoch(), 1);
+        let zero_slots = ChainConfig {
+            seconds_per_slot: 0,
+            ..config
+        };
+        assert!(matches!(
+            zero_slots.slot_clock::<_, 32, 256>(genesis_time, move || genesis_time),
+            Err(ClockError::ZeroSecondsPerSlot)
+        ));
+    }
+
+    #[test]
+    fn test_chain_config_defaults_to_mainnet() {
+        let required: String = MAINNET_CONFIG
+            .lines()
+            .filter(|line| {
+                [
+                    "PRESET_BASE",
+                    "MIN_GENESIS_TIME",
+                    "GENESIS_FORK_VERSION",
+                    "GENESIS_DELAY",
+                    "SECONDS_PER_SLOT",
+                    "MIN_VALIDATOR_WITHDRAWABILITY_DELAY",
+                ]
+                .iter()
+                .any(|key| line.starts_with(&alloc::format!("{key}:")))
+            })
+            .map(|line| alloc::format!("{line}\n"))
+            .collect();
+        let config = ChainConfig::from_yaml(&required).unwrap();
+        let mainnet = ChainConfig::from_yaml(MAINNET_CONFIG).unwrap();
+        assert_eq!(config.forks().len(), 1);
+        assert_eq!(
+            config.min_genesis_active_validator_count,
+            mainnet.min_genesis_active_validator_count
+        );
+        assert_eq!(
+            config.seconds_per_eth1_block,
+            mainnet.seconds_per_eth1_block
+        );
+        assert_eq!(
+            config.shard_committee_period,
+            mainnet.shard_committee_period
+        );
+        assert_eq!(config.eth1_follow_distance, mainnet.eth1_follow_distance);
+        assert_eq!(
+            config.min_per_epoch_churn_limit,
+            mainnet.min_per_epoch_churn_limit
+        );
+        assert_eq!(config.churn_limit_quotient, mainnet.churn_limit_quotient);
+    }
+}
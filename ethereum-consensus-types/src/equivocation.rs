@@ -0,0 +1,229 @@
+use crate::{ForkHeader, ForkSchedule, ForkUpdate, VerificationError};
+use alloc::{vec, vec::Vec};
+use ssz_rs::prelude::*;
+
+/// Evidence of a sync committee attack: two updates of any fork signed by the same sync committee that
+/// commit to different next sync committees, or to different finalized headers at the same slot.
+///
+/// Both updates are kept in full so the evidence can be checked independently, e.g. by a bridge contract.
+#[derive(Clone, Default, Debug, Eq, PartialEq, SimpleSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SyncCommitteeEquivocation<U: ForkUpdate + SimpleSerialize> {
+    pub update_1: U,
+    pub update_2: U,
+}
+
+impl<U: ForkUpdate + SimpleSerialize + Clone> SyncCommitteeEquivocation<U> {
+    /// Check two updates for an equivocation of the sync `committee` that signed them.
+    /// Returns the evidence if they conflict and both verify, `None` if they do not conflict.
+    pub fn detect<const SLOTS_PER_EPOCH: usize, const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize>(
+        update_1: &U,
+        update_2: &U,
+        fork_schedule: &ForkSchedule,
+        committee: &U::SyncCommittee,
+    ) -> Result<Option<Self>, VerificationError> {
+        let evidence = Self {
+            update_1: update_1.clone(),
+            update_2: update_2.clone(),
+        };
+        if !evidence.is_conflicting::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>() {
+            return Ok(None);
+        }
+        evidence.verify::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>(
+            fork_schedule,
+            committee,
+        )?;
+        Ok(Some(evidence))
+    }
+
+    /// Verify that both updates are signed by `committee` and contradict each other
+    pub fn verify<const SLOTS_PER_EPOCH: usize, const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize>(
+        &self,
+        fork_schedule: &ForkSchedule,
+        committee: &U::SyncCommittee,
+    ) -> Result<(), VerificationError> {
+        if !self.is_conflicting::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>() {
+            return Err(VerificationError::NoEquivocation);
+        }
+        for update in [&self.update_1, &self.update_2] {
+            update.verify::<SLOTS_PER_EPOCH>(fork_schedule, committee)?;
+        }
+        Ok(())
+    }
+
+    /// Whether the updates are signed in the same sync committee period and either prove different
+    /// next sync committees for it or different finalized headers at the same slot.
+    /// Signatures and proofs are not checked.
+    pub fn is_conflicting<
+        const SLOTS_PER_EPOCH: usize,
+        const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize,
+    >(
+        &self,
+    ) -> bool {
+        let (update_1, update_2) = (&self.update_1, &self.update_2);
+        let signature_period =
+            update_1.signature_period::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>();
+        if signature_period
+            != update_2.signature_period::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>()
+        {
+            return false;
+        }
+
+        // the next sync committee is only that of the signing committee's successor if attested in its period
+        let proves_next_sync_committee = |update: &U| {
+            update.is_sync_committee_update()
+                && update.attested_period::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>()
+                    == signature_period
+        };
+        let conflicting_next_sync_committee = proves_next_sync_committee(update_1)
+            && proves_next_sync_committee(update_2)
+            && matches!(
+                (update_1.next_sync_committee(), update_2.next_sync_committee()),
+                (Some(proof_1), Some(proof_2)) if proof_1.value != proof_2.value
+            );
+
+        let conflicting_finalized_header = update_1.is_finality_update()
+            && update_2.is_finality_update()
+            && matches!(
+                (update_1.finalized_header(), update_2.finalized_header()),
+                (Some(proof_1), Some(proof_2))
+                    if proof_1.value.beacon().slot == proof_2.value.beacon().slot
+                        && proof_1.value != proof_2.value
+            );
+
+        conflicting_next_sync_committee || conflicting_finalized_header
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::presets::minimal;
+    use crate::test_utils::{merkle_tree, TestCommittee};
+    use crate::{Epoch, ForkSchedule, VerificationError};
+    use ssz_rs::prelude::*;
+
+    const SLOTS_PER_EPOCH: usize = minimal::SLOTS_PER_EPOCH;
+    const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize = minimal::EPOCHS_PER_SYNC_COMMITTEE_PERIOD;
+
+    type Committee = TestCommittee<{ minimal::SYNC_COMMITTEE_SIZE }>;
+
+    fn finality_update(finalized_slot: u64, proposer_index: usize) -> minimal::LightClientUpdate {
+        let mut update = minimal::LightClientUpdate::default();
+        update.attested_header.slot = 20;
+        update.signature_slot = 21;
+        update.finalized_header.slot = finalized_slot;
+        update.finalized_header.proposer_index = proposer_index;
+        update.finality_branch[0] = Node::try_from(&[1u8; 32][..]).unwrap();
+        update
+    }
+
+    #[test]
+    fn test_is_conflicting() {
+        let is_conflicting = |update_1, update_2| {
+            minimal::SyncCommitteeEquivocation { update_1, update_2 }
+                .is_conflicting::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>()
+        };
+
+        assert!(is_conflicting(finality_update(8, 0), finality_update(8, 1)));
+        assert!(!is_conflicting(
+            finality_update(8, 0),
+            finality_update(8, 0)
+        ));
+        assert!(!is_conflicting(
+            finality_update(8, 0),
+            finality_update(16, 1)
+        ));
+
+        // different periods are signed by different committees
+        let mut later = finality_update(8, 1);
+        later.signature_slot = (SLOTS_PER_EPOCH * EPOCHS_PER_SYNC_COMMITTEE_PERIOD) as u64;
+        assert!(!is_conflicting(finality_update(8, 0), later));
+
+        let evidence = minimal::SyncCommitteeEquivocation {
+            update_1: finality_update(8, 0),
+            update_2: finality_update(8, 1),
+        };
+        let bytes = ssz_rs::serialize(&evidence).unwrap();
+        assert_eq!(
+            ssz_rs::deserialize::<minimal::SyncCommitteeEquivocation>(&bytes).unwrap(),
+            evidence
+        );
+    }
+
+    #[test]
+    fn test_is_conflicting_capella() {
+        let capella_update = |finalized_slot, block_number| {
+            let update = finality_update(finalized_slot, 0);
+            let mut capella_update = minimal::LightClientUpdateCapella {
+                finality_branch: update.finality_branch,
+                signature_slot: update.signature_slot,
+                ..Default::default()
+            };
+            capella_update.attested_header.beacon = update.attested_header;
+            capella_update.finalized_header.beacon = update.finalized_header;
+            capella_update.finalized_header.execution.block_number = block_number;
+            capella_update
+        };
+        let is_conflicting = |update_1, update_2| {
+            minimal::SyncCommitteeEquivocationCapella { update_1, update_2 }
+                .is_conflicting::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>()
+        };
+
+        // the finalized headers differ in their execution payload only
+        assert!(is_conflicting(capella_update(8, 1), capella_update(8, 2)));
+        assert!(!is_conflicting(capella_update(8, 1), capella_update(8, 1)));
+        assert!(!is_conflicting(capella_update(8, 1), capella_update(16, 2)));
+    }
+
+    /// An update of `attested_slot` signed by `signer` in the following slot that proves `next_committee`
+    fn sync_committee_update(
+        signer: &Committee,
+        attested_slot: u64,
+        next_committee: &Committee,
+        fork_schedule: &ForkSchedule,
+    ) -> minimal::LightClientUpdate {
+        let root = next_committee.committee.clone().hash_tree_root().unwrap();
+        let (state_root, branches) = merkle_tree(&[(minimal::NEXT_SYNC_COMMITTEE_GINDEX, root)]);
+        let mut update = minimal::LightClientUpdate::default();
+        update.attested_header.slot = attested_slot;
+        update.attested_header.state_root = state_root;
+        update.next_sync_committee = next_committee.committee.clone();
+        update.next_sync_committee_branch = Vector::try_from(branches[0].clone()).unwrap();
+        update.signature_slot = attested_slot + 1;
+        update.sync_aggregate = signer.sign::<SLOTS_PER_EPOCH>(
+            &update.attested_header,
+            update.signature_slot,
+            fork_schedule,
+        );
+        update
+    }
+
+    #[test]
+    fn test_no_equivocation() {
+        // no execution payloads, so headers need no execution branch
+        let fork_schedule = ForkSchedule {
+            capella_fork_epoch: Epoch::MAX,
+            deneb_fork_epoch: Epoch::MAX,
+            ..ForkSchedule::default()
+        };
+        let [first, second, third] = [1, 2, 3].map(Committee::new);
+        let update = sync_committee_update(&first, 24, &second, &fork_schedule);
+        let verify = |update_2| {
+            minimal::SyncCommitteeEquivocation {
+                update_1: update.clone(),
+                update_2,
+            }
+            .verify::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>(
+                &fork_schedule,
+                &first.committee,
+            )
+        };
+
+        assert!(matches!(
+            verify(update.clone()),
+            Err(VerificationError::NoEquivocation)
+        ));
+        let conflicting = sync_committee_update(&first, 32, &third, &fork_schedule);
+        assert!(verify(conflicting).is_ok());
+    }
+}
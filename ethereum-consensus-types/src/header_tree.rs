@@ -0,0 +1,270 @@
+use crate::light_client::{ForkHeader, ForkUpdate};
+use crate::{BeaconBlockHeader, Root, Slot, VerificationError};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::{vec, vec::Vec};
+use ssz_rs::prelude::*;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct HeaderNode {
+    header: BeaconBlockHeader,
+    /// Highest sync committee participation seen for this header
+    participants: usize,
+}
+
+/// The non-finalized headers descending from the latest finalized header, keyed by their
+/// `hash_tree_root` and linked through `parent_root`.
+///
+/// Headers are expected to come from verified optimistic updates, the tree only checks that they
+/// connect to what it already holds.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct HeaderTree {
+    finalized_root: Root,
+    finalized_header: BeaconBlockHeader,
+    headers: BTreeMap<Root, HeaderNode>,
+}
+
+impl HeaderTree {
+    /// Create an empty tree on top of a trusted finalized header
+    pub fn new(finalized_header: BeaconBlockHeader) -> Result<Self, VerificationError> {
+        Ok(Self {
+            finalized_root: finalized_header.clone().hash_tree_root()?,
+            finalized_header,
+            headers: BTreeMap::new(),
+        })
+    }
+
+    pub fn finalized_header(&self) -> &BeaconBlockHeader {
+        &self.finalized_header
+    }
+
+    /// Number of non-finalized headers in the tree
+    pub fn len(&self) -> usize {
+        self.headers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.headers.is_empty()
+    }
+
+    /// Whether `root` is the finalized header or one of the non-finalized headers
+    pub fn contains(&self, root: &Root) -> bool {
+        *root == self.finalized_root || self.headers.contains_key(root)
+    }
+
+    pub fn get(&self, root: &Root) -> Option<&BeaconBlockHeader> {
+        if *root == self.finalized_root {
+            Some(&self.finalized_header)
+        } else {
+            self.headers.get(root).map(|node| &node.header)
+        }
+    }
+
+    /// Add a header signed by `participants` sync committee members and return its root.
+    /// Its parent must already be in the tree. Inserting a known header again only raises its participation.
+    pub fn insert(
+        &mut self,
+        header: BeaconBlockHeader,
+        participants: usize,
+    ) -> Result<Root, VerificationError> {
+        if header.slot <= self.finalized_header.slot {
+            return Err(VerificationError::StaleUpdate {
+                attested_slot: header.slot,
+                finalized_slot: self.finalized_header.slot,
+            });
+        }
+        if !self.contains(&header.parent_root) {
+            return Err(VerificationError::UnknownParentRoot {
+                parent_root: header.parent_root,
+            });
+        }
+        let root = header.clone().hash_tree_root()?;
+        let node = self.headers.entry(root).or_insert(HeaderNode {
+            header,
+            participants: 0,
+        });
+        node.participants = node.participants.max(participants);
+        Ok(root)
+    }
+
+    /// The non-finalized header with the highest slot, preferring the higher sync committee participation
+    /// between headers of the same slot. This is the finalized header if the tree is empty.
+    ///
+    /// This is the fork choice rule of the tree. A light client sees no attestations, so like the
+    /// optimistic header of the sync protocol the head is the most recent header a sync committee
+    /// signed, whichever branch it is on. The branches are not weighed against each other.
+    pub fn head(&self) -> &BeaconBlockHeader {
+        self.headers
+            .values()
+            .max_by_key(|node| (node.header.slot, node.participants))
+            .map(|node| &node.header)
+            .unwrap_or(&self.finalized_header)
+    }
+
+    /// The non-finalized headers from `head` back to the finalized header, newest first
+    pub fn canonical_chain(&self) -> Vec<&BeaconBlockHeader> {
+        let mut chain = vec![];
+        let mut header = self.head();
+        while let Some(node) = self.headers.get(&header.parent_root) {
+            chain.push(header);
+            header = &node.header;
+        }
+        if header.slot > self.finalized_header.slot {
+            chain.push(header);
+        }
+        chain
+    }
+
+    /// Move finality forward to `finalized_header`, dropping every header that does not descend from it.
+    /// Headers at or before the current finalized slot are ignored.
+    pub fn finalize(
+        &mut self,
+        finalized_header: BeaconBlockHeader,
+    ) -> Result<(), VerificationError> {
+        if finalized_header.slot <= self.finalized_header.slot {
+            return Ok(());
+        }
+        let finalized_root = finalized_header.clone().hash_tree_root()?;
+        let keep: BTreeSet<Root> = self
+            .headers
+            .keys()
+            .filter(|root| self.descends_from(**root, finalized_root, finalized_header.slot))
+            .copied()
+            .collect();
+        self.headers.retain(|root, _| keep.contains(root));
+        self.finalized_root = finalized_root;
+        self.finalized_header = finalized_header;
+        Ok(())
+    }
+
+    /// Prune the tree with the finalized header of an already verified update of any fork.
+    /// Updates without a finalized header leave the tree unchanged.
+    pub fn process_finality_update<U: ForkUpdate>(
+        &mut self,
+        update: &U,
+    ) -> Result<(), VerificationError> {
+        match update.finalized_header() {
+            Some(proof) => self.finalize(proof.value.beacon().clone()),
+            None => Ok(()),
+        }
+    }
+
+    fn descends_from(&self, mut root: Root, ancestor: Root, ancestor_slot: Slot) -> bool {
+        while let Some(node) = self.headers.get(&root) {
+            if node.header.slot <= ancestor_slot {
+                return false;
+            }
+            if node.header.parent_root == ancestor {
+                return true;
+            }
+            root = node.header.parent_root;
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::presets::minimal;
+
+    fn child(parent: &BeaconBlockHeader, slot: Slot) -> BeaconBlockHeader {
+        BeaconBlockHeader {
+            slot,
+            parent_root: parent.clone().hash_tree_root().unwrap(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_header_tree() {
+        let genesis = BeaconBlockHeader::default();
+        let mut tree = HeaderTree::new(genesis.clone()).unwrap();
+        assert_eq!(tree.head(), &genesis);
+
+        // genesis <- a1 <- a2 <- a3
+        //         <- b2
+        let a1 = child(&genesis, 1);
+        let a2 = child(&a1, 2);
+        let a3 = child(&a2, 3);
+        let b2 = child(&genesis, 2);
+        tree.insert(a1.clone(), 10).unwrap();
+        tree.insert(a2.clone(), 10).unwrap();
+        tree.insert(b2.clone(), 20).unwrap();
+        assert_eq!(tree.head(), &b2);
+        tree.insert(a3.clone(), 1).unwrap();
+        assert_eq!(tree.head(), &a3);
+        assert_eq!(tree.canonical_chain(), vec![&a3, &a2, &a1]);
+
+        assert!(matches!(
+            tree.insert(child(&child(&a3, 4), 5), 1),
+            Err(VerificationError::UnknownParentRoot { .. })
+        ));
+
+        tree.finalize(a1.clone()).unwrap();
+        assert_eq!(tree.finalized_header(), &a1);
+        assert_eq!(tree.len(), 2);
+        assert!(!tree.contains(&b2.clone().hash_tree_root().unwrap()));
+        assert!(matches!(
+            tree.insert(child(&a1, 1), 1),
+            Err(VerificationError::StaleUpdate { .. })
+        ));
+    }
+
+    #[test]
+    fn test_process_finality_update() {
+        let genesis = BeaconBlockHeader::default();
+        let mut tree = HeaderTree::new(genesis.clone()).unwrap();
+        let a1 = child(&genesis, 1);
+        let a2 = child(&a1, 2);
+        let b2 = child(&genesis, 2);
+        for header in [&a1, &a2, &b2] {
+            tree.insert(header.clone(), 1).unwrap();
+        }
+
+        // optimistic updates carry no finalized header
+        tree.process_finality_update(&minimal::LightClientOptimisticUpdateDeneb::default())
+            .unwrap();
+        assert_eq!(tree.finalized_header(), &genesis);
+
+        let mut update = minimal::LightClientFinalityUpdateDeneb::default();
+        update.finalized_header.beacon = a1.clone();
+        tree.process_finality_update(&update).unwrap();
+        assert_eq!(tree.finalized_header(), &a1);
+        assert_eq!(tree.canonical_chain(), vec![&a2]);
+
+        let mut update = minimal::LightClientUpdate::default();
+        update.finalized_header = a2.clone();
+        tree.process_finality_update(&update).unwrap();
+        assert_eq!(tree.finalized_header(), &a2);
+        assert!(tree.is_empty());
+    }
+
+    #[test]
+    fn test_head_of_competing_branches() {
+        let genesis = BeaconBlockHeader::default();
+        let mut tree = HeaderTree::new(genesis.clone()).unwrap();
+
+        // genesis <- a1 <- a2 <- a3
+        //         <- b1 <- b2
+        let a1 = child(&genesis, 1);
+        let a2 = child(&a1, 2);
+        let a3 = child(&a2, 3);
+        let b1 = BeaconBlockHeader {
+            proposer_index: 1,
+            ..child(&genesis, 1)
+        };
+        let b2 = child(&b1, 2);
+        for (header, participants) in [(&a1, 1), (&a2, 1), (&a3, 1), (&b1, 30), (&b2, 30)] {
+            tree.insert(header.clone(), participants).unwrap();
+        }
+        // the most recent header wins over a better signed branch
+        assert_eq!(tree.head(), &a3);
+        assert_eq!(tree.canonical_chain(), vec![&a3, &a2, &a1]);
+
+        // participation only decides between headers of the same slot
+        let b3 = child(&b2, 3);
+        tree.insert(b3.clone(), 2).unwrap();
+        assert_eq!(tree.head(), &b3);
+        assert_eq!(tree.canonical_chain(), vec![&b3, &b2, &b1]);
+    }
+}
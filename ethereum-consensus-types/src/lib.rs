@@ -1,23 +1,48 @@
-#![cfg_attr(not(feature = "serde"), no_std)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod beacon_block_header;
+pub mod clock;
+pub mod config;
+pub mod equivocation;
+pub mod header_tree;
 pub mod light_client;
+pub mod networks;
+pub mod persistence;
 pub mod presets;
 pub mod primitives;
 pub mod signing;
+pub mod snapshot;
+pub mod spec;
 pub mod sync_committee;
+pub mod weak_subjectivity;
 pub mod bls;
 
 extern crate alloc;
 
+#[cfg(test)]
+mod test_utils;
+
 pub use beacon_block_header::BeaconBlockHeader;
+pub use clock::SlotClock;
+pub use config::ChainConfig;
+pub use equivocation::SyncCommitteeEquivocation;
+pub use header_tree::HeaderTree;
 pub use light_client::{
-    LightClientBootstrap, LightClientFinalityUpdate, LightClientHeader, LightClientUpdate,
-    LightClientUpdateCapella,
+    AnyLightClientStore, ForkBootstrap, ForkHeader, ForkUpdate, LightClientBootstrap,
+    LightClientBootstrapDeneb, LightClientBootstrapElectra, LightClientFinalityUpdate,
+    LightClientFinalityUpdateDeneb, LightClientFinalityUpdateElectra, LightClientHeader,
+    LightClientHeaderDeneb, LightClientHeaderElectra, LightClientOptimisticUpdate,
+    LightClientOptimisticUpdateCapella, LightClientOptimisticUpdateDeneb,
+    LightClientOptimisticUpdateElectra, LightClientStore, LightClientUpdate,
+    LightClientUpdateCapella, LightClientUpdateDeneb, LightClientUpdateElectra, UpdateChainError,
+    VerifiedUpdateChain,
 };
+pub use networks::Network;
 pub use primitives::*;
-pub use signing::ForkData;
-pub use sync_committee::{SyncAggregate, SyncCommittee};
+pub use signing::{ForkData, ForkSchedule};
+pub use snapshot::LightClientStoreSnapshot;
+pub use spec::Spec;
+pub use sync_committee::{AnySyncCommittee, SyncAggregate, SyncCommittee};
 pub use bls::{BlsPublicKey, BlsSignature};
 
 #[derive(Debug)]
@@ -26,6 +51,83 @@ pub enum VerificationError {
     MerklizationError(ssz_rs::MerkleizationError),
     NoSigners,
     InsufficientParticipation,
+    /// The aggregate signature does not match the participating sync committee members
+    SignatureMismatch {
+        signing_root: Root,
+        attested_slot: Slot,
+    },
+    /// `finality_branch` does not prove the finalized header in the attested state
+    InvalidFinalityBranch {
+        finalized_block_root: Root,
+        attested_state_root: Root,
+    },
+    /// `next_sync_committee_branch` does not prove the next sync committee in the attested state
+    InvalidNextSyncCommitteeBranch {
+        next_sync_committee_root: Root,
+        attested_state_root: Root,
+    },
+    /// `current_sync_committee_branch` does not prove the current sync committee in the header state
+    InvalidCurrentSyncCommitteeBranch {
+        current_sync_committee_root: Root,
+        state_root: Root,
+    },
+    /// `execution_branch` does not prove the execution payload header in the block body
+    InvalidExecutionBranch {
+        execution_root: Root,
+        body_root: Root,
+    },
+    /// A header from before Capella carries an execution payload, or one from before Deneb carries blob gas
+    UnexpectedExecutionPayload {
+        slot: Slot,
+    },
+    /// The header does not match the block root it is meant to be verified against
+    UntrustedBlockRoot {
+        block_root: Root,
+        trusted_block_root: Root,
+    },
+    /// `signature_slot` is after the current slot
+    FutureSignatureSlot {
+        signature_slot: Slot,
+        current_slot: Slot,
+    },
+    /// The update violates `signature_slot > attested_slot >= finalized_slot`
+    InvalidSlotOrder {
+        signature_slot: Slot,
+        attested_slot: Slot,
+        finalized_slot: Slot,
+    },
+    /// `next_sync_committee` is set but `next_sync_committee_branch` is zeroed
+    UnprovenNextSyncCommittee,
+    /// `finalized_header` is set but `finality_branch` is zeroed
+    UnprovenFinalizedHeader,
+    /// The update does not prove the sync committee of the period after the one it is signed in
+    MissingNextSyncCommittee {
+        attested_slot: Slot,
+    },
+    /// The update carries a different next sync committee than the one already known to the store
+    NextSyncCommitteeMismatch,
+    /// The header does not connect to any header that is already known
+    UnknownParentRoot {
+        parent_root: Root,
+    },
+    /// The updates do not contradict each other
+    NoEquivocation,
+    /// The checkpoint at `checkpoint_slot` is older than `ws_period` epochs at `current_slot`
+    OutsideWeakSubjectivityPeriod {
+        checkpoint_slot: Slot,
+        current_slot: Slot,
+        ws_period: Epoch,
+    },
+    /// The update does not advance the store
+    StaleUpdate {
+        attested_slot: Slot,
+        finalized_slot: Slot,
+    },
+    /// The sync committee for `period` is not known to a store at `store_period`
+    UnknownSyncCommittee {
+        period: u64,
+        store_period: u64,
+    },
 }
 
 impl From<bls::BlsError> for VerificationError {
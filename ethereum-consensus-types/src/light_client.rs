@@ -1,9 +1,13 @@
-use core::ops::Deref;
-
-use crate::signing::{compute_domain, compute_signing_root, DomainType, ForkData};
+use crate::bls::BlsError;
+use crate::clock::{compute_epoch_at_slot, compute_sync_committee_period_at_slot};
+use crate::signing::{compute_signing_root, ForkSchedule};
+use crate::sync_committee::AnySyncCommittee;
+use crate::weak_subjectivity::verify_weak_subjectivity;
 use crate::{BeaconBlockHeader, SyncAggregate, SyncCommittee, VerificationError};
-use crate::{ByteList, ByteVector, Bytes32, ExecutionAddress, Root, Slot};
+use crate::{ByteList, ByteVector, Bytes32, Epoch, ExecutionAddress, Root, Slot};
 use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+use core::fmt::Debug;
 use ssz_rs::prelude::*;
 use ssz_rs::Merkleized;
 
@@ -51,105 +55,477 @@ pub struct LightClientUpdateCapella<
     pub signature_slot: Slot,
 }
 
+/// An optional field of an update together with the merkle branch proving it at `gindex`
+/// in the state of the attested header
+#[derive(Clone, Copy, Debug)]
+pub struct ProvenField<'a, T, B> {
+    pub value: &'a T,
+    pub branch: &'a [B],
+    pub gindex: usize,
+}
+
+/// A header signed by a sync committee, possibly together with the next sync committee and a finalized
+/// header proven against its state. Every update, finality update and optimistic update of every fork
+/// implements this by exposing its fields, so they share the verification logic below.
+pub trait ForkUpdate {
+    type Header: ForkHeader;
+    type SyncCommittee: AnySyncCommittee;
+    /// Node type of the finality branch, which finality updates encode as `Bytes32`
+    type BranchNode: AsRef<[u8]> + Default + PartialEq;
+
+    fn attested_header(&self) -> &Self::Header;
+
+    fn sync_aggregate(&self) -> &<Self::SyncCommittee as AnySyncCommittee>::SyncAggregate;
+
+    fn signature_slot(&self) -> Slot;
+
+    /// `None` for updates that never carry a next sync committee
+    fn next_sync_committee(&self) -> Option<ProvenField<'_, Self::SyncCommittee, Node>> {
+        None
+    }
+
+    /// `None` for updates that never carry a finalized header
+    fn finalized_header(&self) -> Option<ProvenField<'_, Self::Header, Self::BranchNode>> {
+        None
+    }
+
+    /// Number of sync committee members that signed the attested header
+    fn num_participants(&self) -> usize {
+        Self::SyncCommittee::num_participants(self.sync_aggregate())
+    }
+
+    /// Whether at least 2/3 of the sync committee signed the attested header
+    fn has_supermajority(&self) -> bool {
+        self.num_participants() * 3 >= Self::SyncCommittee::SIZE * 2
+    }
+
+    fn verify<const SLOTS_PER_EPOCH: usize>(
+        &self,
+        fork_schedule: &ForkSchedule,
+        committee: &Self::SyncCommittee,
+    ) -> Result<(), VerificationError> {
+        if !self.has_supermajority() {
+            return Err(VerificationError::InsufficientParticipation);
+        }
+        self.verify_slot_order()?;
+        self.verify_signature::<SLOTS_PER_EPOCH>(fork_schedule, committee)?;
+        self.verify_proofs::<SLOTS_PER_EPOCH>(fork_schedule)
+    }
+
+    /// Slot of the finalized header, zero for updates without one
+    fn finalized_slot(&self) -> Slot {
+        self.finalized_header()
+            .map(|proof| proof.value.beacon().slot)
+            .unwrap_or_default()
+    }
+
+    /// Check the slots of this update are ordered as `signature_slot > attested_slot >= finalized_slot`
+    fn verify_slot_order(&self) -> Result<(), VerificationError> {
+        let signature_slot = self.signature_slot();
+        let attested_slot = self.attested_header().beacon().slot;
+        let finalized_slot = self.finalized_slot();
+        if !(signature_slot > attested_slot && attested_slot >= finalized_slot) {
+            return Err(VerificationError::InvalidSlotOrder {
+                signature_slot,
+                attested_slot,
+                finalized_slot,
+            });
+        }
+        Ok(())
+    }
+
+    /// The checks of `validate_light_client_update` that need no trusted state: enough participants,
+    /// a `signature_slot` that is not after `current_slot` and ordered slots
+    fn validate_slots(&self, current_slot: Slot) -> Result<(), VerificationError> {
+        if self.num_participants() < MIN_SYNC_COMMITTEE_PARTICIPANTS {
+            return Err(VerificationError::InsufficientParticipation);
+        }
+        if self.signature_slot() > current_slot {
+            return Err(VerificationError::FutureSignatureSlot {
+                signature_slot: self.signature_slot(),
+                current_slot,
+            });
+        }
+        self.verify_slot_order()
+    }
+
+    /// Sync committee period of `signature_slot`, whose committee must have signed this update
+    fn signature_period<
+        const SLOTS_PER_EPOCH: usize,
+        const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize,
+    >(
+        &self,
+    ) -> u64 {
+        compute_sync_committee_period_at_slot::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>(
+            self.signature_slot(),
+        )
+    }
+
+    /// Sync committee period of the attested header
+    fn attested_period<
+        const SLOTS_PER_EPOCH: usize,
+        const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize,
+    >(
+        &self,
+    ) -> u64 {
+        compute_sync_committee_period_at_slot::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>(
+            self.attested_header().beacon().slot,
+        )
+    }
+
+    /// Whether this update proves the sync committee following the one that signed it, which is only
+    /// the case if it is attested in the period it is signed in
+    fn has_relevant_sync_committee<
+        const SLOTS_PER_EPOCH: usize,
+        const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize,
+    >(
+        &self,
+    ) -> bool {
+        self.is_sync_committee_update()
+            && self.attested_period::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>()
+                == self.signature_period::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>()
+    }
+
+    /// Verify that the signature included in sync_aggregate is valid for the given sync committee.
+    /// The signed object is the beacon block header of `attested_header`, signed with the fork of
+    /// the slot before `signature_slot`.
+    fn verify_signature<const SLOTS_PER_EPOCH: usize>(
+        &self,
+        fork_schedule: &ForkSchedule,
+        committee: &Self::SyncCommittee,
+    ) -> Result<(), VerificationError> {
+        let attested_header = self.attested_header().beacon();
+        let signing_domain =
+            fork_schedule.sync_committee_domain::<SLOTS_PER_EPOCH>(self.signature_slot())?;
+        let signing_root =
+            compute_signing_root(attested_header.clone().hash_tree_root()?, signing_domain)?;
+        committee
+            .verify_signature(self.sync_aggregate(), signing_root)
+            .map_err(|e| match e {
+                VerificationError::BlsError(BlsError::InvalidSignature) => {
+                    VerificationError::SignatureMismatch {
+                        signing_root,
+                        attested_slot: attested_header.slot,
+                    }
+                }
+                e => e,
+            })
+    }
+
+    /// Whether this update carries a `next_sync_committee` (i.e. its branch is not zeroed)
+    fn is_sync_committee_update(&self) -> bool {
+        self.next_sync_committee()
+            .is_some_and(|proof| !is_zero_branch(proof.branch))
+    }
+
+    /// Whether this update carries a `finalized_header` (i.e. its branch is not zeroed)
+    fn is_finality_update(&self) -> bool {
+        self.finalized_header()
+            .is_some_and(|proof| !is_zero_branch(proof.branch))
+    }
+
+    /// Summarise this update for ranking against other updates of the same sync committee period
+    fn rank<const SLOTS_PER_EPOCH: usize, const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize>(
+        &self,
+    ) -> UpdateRank {
+        UpdateRank::new::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD, Self>(self)
+    }
+
+    /// Whether this update should replace `old` as the best update of a sync committee period
+    ///
+    /// See https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#is_better_update
+    fn is_better_update<
+        const SLOTS_PER_EPOCH: usize,
+        const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize,
+    >(
+        &self,
+        old: &Self,
+    ) -> bool {
+        self.rank::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>()
+            > old.rank::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>()
+    }
+
+    /// Verifies that this data structure is consistent with itself by
+    /// checking all included Merkle proofs, including the execution branches of its headers,
+    /// against its fields.
+    /// A zeroed branch is only accepted together with a zeroed `next_sync_committee` or `finalized_header`.
+    fn verify_proofs<const SLOTS_PER_EPOCH: usize>(
+        &self,
+        fork_schedule: &ForkSchedule,
+    ) -> Result<(), VerificationError> {
+        self.attested_header()
+            .verify_execution_branch::<SLOTS_PER_EPOCH>(fork_schedule)?;
+        if self.is_sync_committee_update() {
+            self.verify_next_sync_committee()?;
+        } else if self
+            .next_sync_committee()
+            .is_some_and(|proof| *proof.value != Self::SyncCommittee::default())
+        {
+            return Err(VerificationError::UnprovenNextSyncCommittee);
+        }
+        let Some(finalized) = self.finalized_header() else {
+            return Ok(());
+        };
+        // non-finality updates, and updates finalizing genesis, carry a zeroed finalized header
+        if *finalized.value == Self::Header::default() {
+            return if self.is_finality_update() {
+                self.verify_finality()
+            } else {
+                Ok(())
+            };
+        }
+        if !self.is_finality_update() {
+            return Err(VerificationError::UnprovenFinalizedHeader);
+        }
+        finalized
+            .value
+            .verify_execution_branch::<SLOTS_PER_EPOCH>(fork_schedule)?;
+        self.verify_finality()
+    }
+
+    /// Verifies the `next_sync_committee` field of this struct is valid by
+    /// checking a merkle proof that the committee is contained in the state which is
+    /// rooted in the attested header.
+    fn verify_next_sync_committee(&self) -> Result<(), VerificationError> {
+        let proof = self
+            .next_sync_committee()
+            .ok_or(VerificationError::UnprovenNextSyncCommittee)?;
+        let next_sync_committee_root = proof.value.clone().hash_tree_root()?;
+        let attested_state_root = self.attested_header().beacon().state_root;
+        if !is_valid_branch(
+            next_sync_committee_root,
+            proof.branch,
+            proof.gindex,
+            attested_state_root,
+        ) {
+            return Err(VerificationError::InvalidNextSyncCommitteeBranch {
+                next_sync_committee_root,
+                attested_state_root,
+            });
+        }
+        Ok(())
+    }
+
+    /// Verifies the finalized header is the `finalized_checkpoint` of the state
+    /// rooted in the attested header.
+    fn verify_finality(&self) -> Result<(), VerificationError> {
+        let proof = self
+            .finalized_header()
+            .ok_or(VerificationError::UnprovenFinalizedHeader)?;
+        let finalized_block_root = finalized_block_root(proof.value.beacon())?;
+        let attested_state_root = self.attested_header().beacon().state_root;
+        if !is_valid_branch(
+            finalized_block_root,
+            proof.branch,
+            proof.gindex,
+            attested_state_root,
+        ) {
+            return Err(VerificationError::InvalidFinalityBranch {
+                finalized_block_root,
+                attested_state_root,
+            });
+        }
+        Ok(())
+    }
+}
+
 impl<
         const SYNC_COMMITTEE_SIZE: usize,
         const NEXT_SYNC_COMMITTEE_GINDEX: usize,
         const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize,
         const FINALIZED_ROOT_GINDEX: usize,
         const FINALIZED_ROOT_PROOF_SIZE: usize,
+    > ForkUpdate
+    for LightClientUpdate<
+        SYNC_COMMITTEE_SIZE,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+        FINALIZED_ROOT_GINDEX,
+        FINALIZED_ROOT_PROOF_SIZE,
     >
-    LightClientUpdate<
+{
+    type Header = BeaconBlockHeader;
+    type SyncCommittee = SyncCommittee<SYNC_COMMITTEE_SIZE>;
+    type BranchNode = Node;
+
+    fn attested_header(&self) -> &Self::Header {
+        &self.attested_header
+    }
+
+    fn sync_aggregate(&self) -> &SyncAggregate<SYNC_COMMITTEE_SIZE> {
+        &self.sync_aggregate
+    }
+
+    fn signature_slot(&self) -> Slot {
+        self.signature_slot
+    }
+
+    fn next_sync_committee(&self) -> Option<ProvenField<'_, Self::SyncCommittee, Node>> {
+        Some(ProvenField {
+            value: &self.next_sync_committee,
+            branch: &self.next_sync_committee_branch,
+            gindex: NEXT_SYNC_COMMITTEE_GINDEX,
+        })
+    }
+
+    fn finalized_header(&self) -> Option<ProvenField<'_, Self::Header, Node>> {
+        Some(ProvenField {
+            value: &self.finalized_header,
+            branch: &self.finality_branch,
+            gindex: FINALIZED_ROOT_GINDEX,
+        })
+    }
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const NEXT_SYNC_COMMITTEE_GINDEX: usize,
+        const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize,
+        const FINALIZED_ROOT_GINDEX: usize,
+        const FINALIZED_ROOT_PROOF_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    > ForkUpdate
+    for LightClientUpdateCapella<
         SYNC_COMMITTEE_SIZE,
         NEXT_SYNC_COMMITTEE_GINDEX,
         NEXT_SYNC_COMMITTEE_PROOF_SIZE,
         FINALIZED_ROOT_GINDEX,
         FINALIZED_ROOT_PROOF_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
     >
 {
-    pub fn verify(
-        &self,
-        fork_data: &ForkData,
-        committee: &SyncCommittee<SYNC_COMMITTEE_SIZE>,
-    ) -> Result<bool, VerificationError> {
-        self.sync_aggregate.verify_participation()?;
-        self.verify_signature(fork_data, committee)?;
-        Ok(
-            self.verify_proofs()?
-                && self.verify_next_sync_committee()?
-                && self.verify_finality()?,
-        )
+    type Header = LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>;
+    type SyncCommittee = SyncCommittee<SYNC_COMMITTEE_SIZE>;
+    type BranchNode = Node;
+
+    fn attested_header(&self) -> &Self::Header {
+        &self.attested_header
     }
 
-    /// Verify that the signature included in sync_aggregate is valid for the given sync committee.
-    /// This is done by aggregating the committee public keys according to the sync committee bits and using this
-    /// to check the signature over the finalized_header.
-    pub fn verify_signature(
-        &self,
-        fork_data: &ForkData,
-        committee: &SyncCommittee<SYNC_COMMITTEE_SIZE>,
-    ) -> Result<(), VerificationError> {
-        let aggregate_sig = committee
-            .aggregate_pubkey(&self.sync_aggregate.sync_committee_bits)
-            .ok_or(VerificationError::NoSigners)?;
-
-        let signing_domain = compute_domain(DomainType::SyncCommittee, fork_data)?;
-        aggregate_sig.verify_signature(
-            compute_signing_root(
-                self.attested_header.clone().hash_tree_root()?,
-                signing_domain,
-            )?
-            .as_ref(),
-            &self.sync_aggregate.sync_committee_signature,
-        )?;
+    fn sync_aggregate(&self) -> &SyncAggregate<SYNC_COMMITTEE_SIZE> {
+        &self.sync_aggregate
+    }
 
-        Ok(())
+    fn signature_slot(&self) -> Slot {
+        self.signature_slot
     }
 
-    /// Verifies that this data structure is consistent with itself by
-    /// checking both included Merkle proofs against its fields
-    pub fn verify_proofs(&self) -> Result<bool, VerificationError> {
-        Ok(self.verify_next_sync_committee()? && self.verify_finality()?)
+    fn next_sync_committee(&self) -> Option<ProvenField<'_, Self::SyncCommittee, Node>> {
+        Some(ProvenField {
+            value: &self.next_sync_committee,
+            branch: &self.next_sync_committee_branch,
+            gindex: NEXT_SYNC_COMMITTEE_GINDEX,
+        })
     }
 
-    /// Verifies the `next_sync_committee` field of this struct is valid by
-    /// checking a merkle proof that the committee is contained in the state which is
-    /// rooted in the` attested_header`.
-    pub fn verify_next_sync_committee(&self) -> Result<bool, VerificationError> {
-        let next_sync_committee_root = self.next_sync_committee.clone().hash_tree_root().unwrap();
-        Ok(is_valid_merkle_branch(
-            next_sync_committee_root,
-            &self
-                .next_sync_committee_branch
-                .iter()
-                .map(|node| node.deref())
-                .collect::<Vec<_>>(),
-            NEXT_SYNC_COMMITTEE_PROOF_SIZE,
-            NEXT_SYNC_COMMITTEE_GINDEX,
-            self.attested_header.state_root,
-        )
-        .is_ok())
+    fn finalized_header(&self) -> Option<ProvenField<'_, Self::Header, Node>> {
+        Some(ProvenField {
+            value: &self.finalized_header,
+            branch: &self.finality_branch,
+            gindex: FINALIZED_ROOT_GINDEX,
+        })
+    }
+}
+
+/// Root of `header` as committed to by the `finalized_checkpoint` of a beacon state.
+/// The genesis finalized checkpoint root is represented as a zero hash, so a zeroed header maps to it.
+fn finalized_block_root(header: &BeaconBlockHeader) -> Result<Root, VerificationError> {
+    if *header == BeaconBlockHeader::default() {
+        Ok(Root::default())
+    } else {
+        Ok(header.clone().hash_tree_root()?)
     }
+}
 
-    /// Verifies the given attested_header has been finalized by checking a merkle proof
-    /// that it is contained in the `finalized_checkpoint` field in the state rooted
-    /// in the given `finalized_header`.
-    pub fn verify_finality(&self) -> Result<bool, VerificationError> {
-        let finalized_block_root = self.finalized_header.clone().hash_tree_root().unwrap();
-        Ok(is_valid_merkle_branch(
-            finalized_block_root,
-            &self
-                .finality_branch
-                .iter()
-                .map(|node| node.deref())
-                .collect::<Vec<_>>(),
-            FINALIZED_ROOT_PROOF_SIZE,
-            FINALIZED_ROOT_GINDEX,
-            self.attested_header.state_root,
-        )
-        .is_ok())
+/// Whether `branch` is all zeroes, which marks an absent optional field of an update
+fn is_zero_branch<T: Default + PartialEq>(branch: &[T]) -> bool {
+    branch.iter().all(|node| *node == T::default())
+}
+
+/// The properties of a light client update that decide which update is kept as the best one
+/// for a sync committee period. Better updates compare greater.
+///
+/// See https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#is_better_update
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UpdateRank {
+    has_supermajority: bool,
+    num_participants: usize,
+    has_relevant_sync_committee: bool,
+    has_finality: bool,
+    has_sync_committee_finality: bool,
+    attested_slot: Slot,
+    signature_slot: Slot,
+}
+
+impl UpdateRank {
+    fn new<
+        const SLOTS_PER_EPOCH: usize,
+        const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize,
+        U: ForkUpdate + ?Sized,
+    >(
+        update: &U,
+    ) -> Self {
+        let attested_period =
+            update.attested_period::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>();
+        let finalized_period = compute_sync_committee_period_at_slot::<
+            SLOTS_PER_EPOCH,
+            EPOCHS_PER_SYNC_COMMITTEE_PERIOD,
+        >(update.finalized_slot());
+        let is_finality_update = update.is_finality_update();
+        Self {
+            has_supermajority: update.has_supermajority(),
+            num_participants: update.num_participants(),
+            has_relevant_sync_committee: update
+                .has_relevant_sync_committee::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>(),
+            has_finality: is_finality_update,
+            has_sync_committee_finality: is_finality_update && finalized_period == attested_period,
+            attested_slot: update.attested_header().beacon().slot,
+            signature_slot: update.signature_slot(),
+        }
+    }
+}
+
+impl Ord for UpdateRank {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.has_supermajority
+            .cmp(&other.has_supermajority)
+            .then_with(|| {
+                // without a supermajority participation matters more than anything else
+                if self.has_supermajority {
+                    Ordering::Equal
+                } else {
+                    self.num_participants.cmp(&other.num_participants)
+                }
+            })
+            .then(
+                self.has_relevant_sync_committee
+                    .cmp(&other.has_relevant_sync_committee),
+            )
+            .then(self.has_finality.cmp(&other.has_finality))
+            .then(
+                self.has_sync_committee_finality
+                    .cmp(&other.has_sync_committee_finality),
+            )
+            .then(self.num_participants.cmp(&other.num_participants))
+            // prefer older data to reduce churn of the best update
+            .then(other.attested_slot.cmp(&self.attested_slot))
+            .then(other.signature_slot.cmp(&self.signature_slot))
+    }
+}
+
+impl PartialOrd for UpdateRank {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
+/// Check that `branch` proves `leaf` is at `gindex` in the merkle tree with the given `root`
+fn is_valid_branch<T: AsRef<[u8]>>(leaf: Root, branch: &[T], gindex: usize, root: Root) -> bool {
+    is_valid_merkle_branch(leaf, branch, branch.len(), gindex, root).is_ok()
+}
+
 #[derive(Default, Debug, Clone, SimpleSerialize, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExecutionPayloadHeader<
@@ -177,6 +553,73 @@ pub struct ExecutionPayloadHeader<
     pub withdrawals_root: Root,
 }
 
+/// Generalized index of `execution_payload` in the `BeaconBlockBody`
+pub const EXECUTION_PAYLOAD_GINDEX: usize = 25;
+
+/// Minimum number of sync committee participants for an update to be considered at all.
+/// This is the same in every preset.
+pub const MIN_SYNC_COMMITTEE_PARTICIPANTS: usize = 1;
+
+/// The header of a light client data structure of any fork.
+/// Forks only differ in the execution payload that is proven against the block body.
+pub trait ForkHeader: Clone + Debug + Default + PartialEq {
+    fn beacon(&self) -> &BeaconBlockHeader;
+
+    /// Verifies the execution payload of this header is committed to in the block body
+    /// rooted in `beacon().body_root`, or is empty if the header predates execution payloads
+    /// according to `fork_schedule`.
+    fn verify_execution_branch<const SLOTS_PER_EPOCH: usize>(
+        &self,
+        fork_schedule: &ForkSchedule,
+    ) -> Result<(), VerificationError>;
+}
+
+/// Headers before Capella are plain beacon block headers without an execution payload
+impl ForkHeader for BeaconBlockHeader {
+    fn beacon(&self) -> &BeaconBlockHeader {
+        self
+    }
+
+    fn verify_execution_branch<const SLOTS_PER_EPOCH: usize>(
+        &self,
+        _fork_schedule: &ForkSchedule,
+    ) -> Result<(), VerificationError> {
+        Ok(())
+    }
+}
+
+/// Verifies `execution` is committed to in the block body rooted in `beacon.body_root`.
+/// Before Capella there is no execution payload, so it must be empty and its branch zeroed instead.
+fn verify_execution_payload<
+    P: Merkleized + Clone + Default + PartialEq,
+    const SLOTS_PER_EPOCH: usize,
+>(
+    beacon: &BeaconBlockHeader,
+    execution: &P,
+    execution_branch: &[Bytes32],
+    fork_schedule: &ForkSchedule,
+) -> Result<(), VerificationError> {
+    if compute_epoch_at_slot::<SLOTS_PER_EPOCH>(beacon.slot) < fork_schedule.capella_fork_epoch {
+        if *execution != P::default() || !is_zero_branch(execution_branch) {
+            return Err(VerificationError::UnexpectedExecutionPayload { slot: beacon.slot });
+        }
+        return Ok(());
+    }
+    let execution_root = execution.clone().hash_tree_root()?;
+    if !is_valid_branch(
+        execution_root,
+        execution_branch,
+        EXECUTION_PAYLOAD_GINDEX,
+        beacon.body_root,
+    ) {
+        return Err(VerificationError::InvalidExecutionBranch {
+            execution_root,
+            body_root: beacon.body_root,
+        });
+    }
+    Ok(())
+}
+
 #[derive(Clone, Default, Debug, Eq, PartialEq, SimpleSerialize)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LightClientHeader<const BYTES_PER_LOGS_BLOOM: usize, const MAX_EXTRA_DATA_BYTES: usize> {
@@ -185,23 +628,149 @@ pub struct LightClientHeader<const BYTES_PER_LOGS_BLOOM: usize, const MAX_EXTRA_
     pub execution_branch: Vector<Bytes32, 4>,
 }
 
+impl<const BYTES_PER_LOGS_BLOOM: usize, const MAX_EXTRA_DATA_BYTES: usize> ForkHeader
+    for LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+{
+    fn beacon(&self) -> &BeaconBlockHeader {
+        &self.beacon
+    }
+
+    /// See https://github.com/ethereum/consensus-specs/blob/dev/specs/capella/light-client/sync-protocol.md#modified-is_valid_light_client_header
+    fn verify_execution_branch<const SLOTS_PER_EPOCH: usize>(
+        &self,
+        fork_schedule: &ForkSchedule,
+    ) -> Result<(), VerificationError> {
+        verify_execution_payload::<_, SLOTS_PER_EPOCH>(
+            &self.beacon,
+            &self.execution,
+            &self.execution_branch,
+            fork_schedule,
+        )
+    }
+}
+
+/// The beacon block header of a verified header, for code that only tracks beacon block headers
+impl<const BYTES_PER_LOGS_BLOOM: usize, const MAX_EXTRA_DATA_BYTES: usize>
+    From<LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>> for BeaconBlockHeader
+{
+    fn from(header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>) -> Self {
+        header.beacon
+    }
+}
+
+/// A bootstrap of any fork, from which a light client store is initialized
+pub trait ForkBootstrap {
+    type Header: ForkHeader;
+    type SyncCommittee: AnySyncCommittee;
+
+    fn header(&self) -> &Self::Header;
+
+    /// The sync committee of the period of `header`, proven against its state
+    fn current_sync_committee(&self) -> ProvenField<'_, Self::SyncCommittee, Node>;
+
+    /// Verify this bootstrap against a block root obtained out of band, e.g. from a weak subjectivity checkpoint.
+    /// The header must hash to `trusted_block_root` and the current sync committee must be proven against its state.
+    fn verify<const SLOTS_PER_EPOCH: usize>(
+        &self,
+        trusted_block_root: Root,
+        fork_schedule: &ForkSchedule,
+    ) -> Result<(), VerificationError> {
+        let block_root = self.header().beacon().clone().hash_tree_root()?;
+        if block_root != trusted_block_root {
+            return Err(VerificationError::UntrustedBlockRoot {
+                block_root,
+                trusted_block_root,
+            });
+        }
+        self.header()
+            .verify_execution_branch::<SLOTS_PER_EPOCH>(fork_schedule)?;
+        self.verify_current_sync_committee()
+    }
+
+    /// Reject a bootstrap whose header is too old to be trusted at `current_slot`
+    fn verify_weak_subjectivity<const SLOTS_PER_EPOCH: usize>(
+        &self,
+        current_slot: Slot,
+        ws_period: Epoch,
+    ) -> Result<(), VerificationError> {
+        verify_weak_subjectivity::<SLOTS_PER_EPOCH>(
+            self.header().beacon().slot,
+            current_slot,
+            ws_period,
+        )
+    }
+
+    /// Verifies the current sync committee is valid by checking a merkle proof that
+    /// it is contained in the state which is rooted in the header.
+    fn verify_current_sync_committee(&self) -> Result<(), VerificationError> {
+        let proof = self.current_sync_committee();
+        let current_sync_committee_root = proof.value.clone().hash_tree_root()?;
+        let state_root = self.header().beacon().state_root;
+        if !is_valid_branch(
+            current_sync_committee_root,
+            proof.branch,
+            proof.gindex,
+            state_root,
+        ) {
+            return Err(VerificationError::InvalidCurrentSyncCommitteeBranch {
+                current_sync_committee_root,
+                state_root,
+            });
+        }
+        Ok(())
+    }
+}
+
 #[derive(Clone, Default, Debug, Eq, PartialEq, SimpleSerialize)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LightClientBootstrap<
     const SYNC_COMMITTEE_SIZE: usize,
-    const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize,
+    const CURRENT_SYNC_COMMITTEE_GINDEX: usize,
+    const CURRENT_SYNC_COMMITTEE_PROOF_SIZE: usize,
     const BYTES_PER_LOGS_BLOOM: usize,
     const MAX_EXTRA_DATA_BYTES: usize,
 > {
     pub header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
     pub current_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
-    pub current_sync_committee_branch: Vector<Node, NEXT_SYNC_COMMITTEE_PROOF_SIZE>,
+    pub current_sync_committee_branch: Vector<Node, CURRENT_SYNC_COMMITTEE_PROOF_SIZE>,
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const CURRENT_SYNC_COMMITTEE_GINDEX: usize,
+        const CURRENT_SYNC_COMMITTEE_PROOF_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    > ForkBootstrap
+    for LightClientBootstrap<
+        SYNC_COMMITTEE_SIZE,
+        CURRENT_SYNC_COMMITTEE_GINDEX,
+        CURRENT_SYNC_COMMITTEE_PROOF_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >
+{
+    type Header = LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>;
+    type SyncCommittee = SyncCommittee<SYNC_COMMITTEE_SIZE>;
+
+    fn header(&self) -> &Self::Header {
+        &self.header
+    }
+
+    fn current_sync_committee(&self) -> ProvenField<'_, Self::SyncCommittee, Node> {
+        ProvenField {
+            value: &self.current_sync_committee,
+            branch: &self.current_sync_committee_branch,
+            gindex: CURRENT_SYNC_COMMITTEE_GINDEX,
+        }
+    }
 }
 
 #[derive(Default, Debug, Eq, PartialEq, Clone, SimpleSerialize)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LightClientFinalityUpdate<
     const SYNC_COMMITTEE_SIZE: usize,
+    const FINALIZED_ROOT_GINDEX: usize,
     const FINALIZED_ROOT_PROOF_SIZE: usize,
     const BYTES_PER_LOGS_BLOOM: usize,
     const MAX_EXTRA_DATA_BYTES: usize,
@@ -213,3 +782,2342 @@ pub struct LightClientFinalityUpdate<
     #[cfg_attr(feature = "serde", serde(with = "crate::as_str"))]
     pub signature_slot: Slot,
 }
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const FINALIZED_ROOT_GINDEX: usize,
+        const FINALIZED_ROOT_PROOF_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    > ForkUpdate
+    for LightClientFinalityUpdate<
+        SYNC_COMMITTEE_SIZE,
+        FINALIZED_ROOT_GINDEX,
+        FINALIZED_ROOT_PROOF_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >
+{
+    type Header = LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>;
+    type SyncCommittee = SyncCommittee<SYNC_COMMITTEE_SIZE>;
+    type BranchNode = Bytes32;
+
+    fn attested_header(&self) -> &Self::Header {
+        &self.attested_header
+    }
+
+    fn sync_aggregate(&self) -> &SyncAggregate<SYNC_COMMITTEE_SIZE> {
+        &self.sync_aggregate
+    }
+
+    fn signature_slot(&self) -> Slot {
+        self.signature_slot
+    }
+
+    fn finalized_header(&self) -> Option<ProvenField<'_, Self::Header, Bytes32>> {
+        Some(ProvenField {
+            value: &self.finalized_header,
+            branch: &self.finality_branch,
+            gindex: FINALIZED_ROOT_GINDEX,
+        })
+    }
+}
+
+/// Carry over a finality branch that finality updates encode as `Bytes32` to the `Node`s of an update.
+/// Fails with `InvalidFinalityBranch` for the given headers if a node cannot be converted.
+fn finality_branch_nodes<const FINALIZED_ROOT_PROOF_SIZE: usize>(
+    branch: &Vector<Bytes32, FINALIZED_ROOT_PROOF_SIZE>,
+    finalized_header: &BeaconBlockHeader,
+    attested_header: &BeaconBlockHeader,
+) -> Result<Vector<Node, FINALIZED_ROOT_PROOF_SIZE>, VerificationError> {
+    let nodes = branch
+        .iter()
+        .map(|node| Node::try_from(node.as_ref()))
+        .collect::<Result<Vec<_>, _>>()
+        .ok()
+        .and_then(|branch| branch.try_into().ok());
+    match nodes {
+        Some(nodes) => Ok(nodes),
+        None => Err(VerificationError::InvalidFinalityBranch {
+            finalized_block_root: finalized_block_root(finalized_header)?,
+            attested_state_root: attested_header.state_root,
+        }),
+    }
+}
+
+/// A finality update is a `LightClientUpdate` without a next sync committee, so it is converted with
+/// `next_sync_committee` and its branch zeroed.
+/// Fails with `InvalidFinalityBranch` if the finality branch cannot be carried over.
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const NEXT_SYNC_COMMITTEE_GINDEX: usize,
+        const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize,
+        const FINALIZED_ROOT_GINDEX: usize,
+        const FINALIZED_ROOT_PROOF_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    >
+    TryFrom<
+        &LightClientFinalityUpdate<
+            SYNC_COMMITTEE_SIZE,
+            FINALIZED_ROOT_GINDEX,
+            FINALIZED_ROOT_PROOF_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >,
+    >
+    for LightClientUpdate<
+        SYNC_COMMITTEE_SIZE,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+        FINALIZED_ROOT_GINDEX,
+        FINALIZED_ROOT_PROOF_SIZE,
+    >
+{
+    type Error = VerificationError;
+
+    fn try_from(
+        update: &LightClientFinalityUpdate<
+            SYNC_COMMITTEE_SIZE,
+            FINALIZED_ROOT_GINDEX,
+            FINALIZED_ROOT_PROOF_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            attested_header: update.attested_header.beacon.clone(),
+            next_sync_committee: SyncCommittee::default(),
+            next_sync_committee_branch: Vector::default(),
+            finalized_header: update.finalized_header.beacon.clone(),
+            finality_branch: finality_branch_nodes(
+                &update.finality_branch,
+                &update.finalized_header.beacon,
+                &update.attested_header.beacon,
+            )?,
+            sync_aggregate: update.sync_aggregate.clone(),
+            signature_slot: update.signature_slot,
+        })
+    }
+}
+
+/// An optimistic update is a `LightClientUpdate` without a next sync committee or finalized header,
+/// so it is converted with both of them and their branches zeroed.
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const NEXT_SYNC_COMMITTEE_GINDEX: usize,
+        const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize,
+        const FINALIZED_ROOT_GINDEX: usize,
+        const FINALIZED_ROOT_PROOF_SIZE: usize,
+    > From<&LightClientOptimisticUpdate<SYNC_COMMITTEE_SIZE>>
+    for LightClientUpdate<
+        SYNC_COMMITTEE_SIZE,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+        FINALIZED_ROOT_GINDEX,
+        FINALIZED_ROOT_PROOF_SIZE,
+    >
+{
+    fn from(update: &LightClientOptimisticUpdate<SYNC_COMMITTEE_SIZE>) -> Self {
+        Self {
+            attested_header: update.attested_header.clone(),
+            sync_aggregate: update.sync_aggregate.clone(),
+            signature_slot: update.signature_slot,
+            ..Default::default()
+        }
+    }
+}
+
+/// Captures the data needed to follow the head of the chain between finality updates.
+///
+/// See https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#lightclientoptimisticupdate
+#[derive(Default, Debug, Eq, PartialEq, Clone, SimpleSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LightClientOptimisticUpdate<const SYNC_COMMITTEE_SIZE: usize> {
+    pub attested_header: BeaconBlockHeader,
+    pub sync_aggregate: SyncAggregate<SYNC_COMMITTEE_SIZE>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::as_str"))]
+    pub signature_slot: Slot,
+}
+
+impl<const SYNC_COMMITTEE_SIZE: usize> ForkUpdate
+    for LightClientOptimisticUpdate<SYNC_COMMITTEE_SIZE>
+{
+    type Header = BeaconBlockHeader;
+    type SyncCommittee = SyncCommittee<SYNC_COMMITTEE_SIZE>;
+    type BranchNode = Node;
+
+    fn attested_header(&self) -> &Self::Header {
+        &self.attested_header
+    }
+
+    fn sync_aggregate(&self) -> &SyncAggregate<SYNC_COMMITTEE_SIZE> {
+        &self.sync_aggregate
+    }
+
+    fn signature_slot(&self) -> Slot {
+        self.signature_slot
+    }
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Clone, SimpleSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LightClientOptimisticUpdateCapella<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> {
+    pub attested_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub sync_aggregate: SyncAggregate<SYNC_COMMITTEE_SIZE>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::as_str"))]
+    pub signature_slot: Slot,
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    > ForkUpdate
+    for LightClientOptimisticUpdateCapella<
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >
+{
+    type Header = LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>;
+    type SyncCommittee = SyncCommittee<SYNC_COMMITTEE_SIZE>;
+    type BranchNode = Node;
+
+    fn attested_header(&self) -> &Self::Header {
+        &self.attested_header
+    }
+
+    fn sync_aggregate(&self) -> &SyncAggregate<SYNC_COMMITTEE_SIZE> {
+        &self.sync_aggregate
+    }
+
+    fn signature_slot(&self) -> Slot {
+        self.signature_slot
+    }
+}
+
+/// Converts a Capella finality update into a `LightClientUpdateCapella` with `next_sync_committee` and its branch
+/// zeroed, keeping the execution payload headers.
+/// Fails with `InvalidFinalityBranch` if the finality branch cannot be carried over.
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const NEXT_SYNC_COMMITTEE_GINDEX: usize,
+        const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize,
+        const FINALIZED_ROOT_GINDEX: usize,
+        const FINALIZED_ROOT_PROOF_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    >
+    TryFrom<
+        &LightClientFinalityUpdate<
+            SYNC_COMMITTEE_SIZE,
+            FINALIZED_ROOT_GINDEX,
+            FINALIZED_ROOT_PROOF_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >,
+    >
+    for LightClientUpdateCapella<
+        SYNC_COMMITTEE_SIZE,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+        FINALIZED_ROOT_GINDEX,
+        FINALIZED_ROOT_PROOF_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >
+{
+    type Error = VerificationError;
+
+    fn try_from(
+        update: &LightClientFinalityUpdate<
+            SYNC_COMMITTEE_SIZE,
+            FINALIZED_ROOT_GINDEX,
+            FINALIZED_ROOT_PROOF_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            attested_header: update.attested_header.clone(),
+            next_sync_committee: SyncCommittee::default(),
+            next_sync_committee_branch: Vector::default(),
+            finalized_header: update.finalized_header.clone(),
+            finality_branch: finality_branch_nodes(
+                &update.finality_branch,
+                &update.finalized_header.beacon,
+                &update.attested_header.beacon,
+            )?,
+            sync_aggregate: update.sync_aggregate.clone(),
+            signature_slot: update.signature_slot,
+        })
+    }
+}
+
+/// Converts a Capella optimistic update into a `LightClientUpdateCapella` with the next sync committee, the
+/// finalized header and their branches zeroed, keeping the execution payload header.
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const NEXT_SYNC_COMMITTEE_GINDEX: usize,
+        const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize,
+        const FINALIZED_ROOT_GINDEX: usize,
+        const FINALIZED_ROOT_PROOF_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    >
+    From<
+        &LightClientOptimisticUpdateCapella<
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >,
+    >
+    for LightClientUpdateCapella<
+        SYNC_COMMITTEE_SIZE,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+        FINALIZED_ROOT_GINDEX,
+        FINALIZED_ROOT_PROOF_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >
+{
+    fn from(
+        update: &LightClientOptimisticUpdateCapella<
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >,
+    ) -> Self {
+        Self {
+            attested_header: update.attested_header.clone(),
+            sync_aggregate: update.sync_aggregate.clone(),
+            signature_slot: update.signature_slot,
+            ..Default::default()
+        }
+    }
+}
+
+/// Execution payload header with the blob gas accounting fields added in Deneb
+#[derive(Default, Debug, Clone, SimpleSerialize, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExecutionPayloadHeaderDeneb<
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> {
+    pub parent_hash: Root,
+    pub fee_recipient: ExecutionAddress,
+    pub state_root: Root,
+    pub receipts_root: Root,
+    pub logs_bloom: ByteVector<BYTES_PER_LOGS_BLOOM>,
+    pub prev_randao: Root,
+    #[cfg_attr(feature = "serde", serde(with = "crate::as_str"))]
+    pub block_number: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::as_str"))]
+    pub gas_limit: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::as_str"))]
+    pub gas_used: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::as_str"))]
+    pub timestamp: u64,
+    pub extra_data: ByteList<MAX_EXTRA_DATA_BYTES>,
+    pub base_fee_per_gas: U256,
+    pub block_hash: Root,
+    pub transactions_root: Root,
+    pub withdrawals_root: Root,
+    #[cfg_attr(feature = "serde", serde(with = "crate::as_str"))]
+    pub blob_gas_used: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::as_str"))]
+    pub excess_blob_gas: u64,
+}
+
+/// A Deneb payload header of a block before Deneb is committed to as the Capella payload header
+/// with the same fields, as the block body has no blob gas fields yet
+impl<const BYTES_PER_LOGS_BLOOM: usize, const MAX_EXTRA_DATA_BYTES: usize>
+    From<&ExecutionPayloadHeaderDeneb<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>
+    for ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+{
+    fn from(
+        header: &ExecutionPayloadHeaderDeneb<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    ) -> Self {
+        Self {
+            parent_hash: header.parent_hash,
+            fee_recipient: header.fee_recipient.clone(),
+            state_root: header.state_root,
+            receipts_root: header.receipts_root,
+            logs_bloom: header.logs_bloom.clone(),
+            prev_randao: header.prev_randao,
+            block_number: header.block_number,
+            gas_limit: header.gas_limit,
+            gas_used: header.gas_used,
+            timestamp: header.timestamp,
+            extra_data: header.extra_data.clone(),
+            base_fee_per_gas: header.base_fee_per_gas.clone(),
+            block_hash: header.block_hash,
+            transactions_root: header.transactions_root,
+            withdrawals_root: header.withdrawals_root,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug, Eq, PartialEq, SimpleSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LightClientHeaderDeneb<
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> {
+    pub beacon: BeaconBlockHeader,
+    pub execution: ExecutionPayloadHeaderDeneb<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub execution_branch: Vector<Bytes32, 4>,
+}
+
+impl<const BYTES_PER_LOGS_BLOOM: usize, const MAX_EXTRA_DATA_BYTES: usize> ForkHeader
+    for LightClientHeaderDeneb<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+{
+    fn beacon(&self) -> &BeaconBlockHeader {
+        &self.beacon
+    }
+
+    /// Before Deneb the payload is proven as the Capella payload header, see `get_lc_execution_root`.
+    ///
+    /// See https://github.com/ethereum/consensus-specs/blob/dev/specs/deneb/light-client/sync-protocol.md#modified-is_valid_light_client_header
+    fn verify_execution_branch<const SLOTS_PER_EPOCH: usize>(
+        &self,
+        fork_schedule: &ForkSchedule,
+    ) -> Result<(), VerificationError> {
+        if compute_epoch_at_slot::<SLOTS_PER_EPOCH>(self.beacon.slot)
+            < fork_schedule.deneb_fork_epoch
+        {
+            if self.execution.blob_gas_used != 0 || self.execution.excess_blob_gas != 0 {
+                return Err(VerificationError::UnexpectedExecutionPayload {
+                    slot: self.beacon.slot,
+                });
+            }
+            return verify_execution_payload::<_, SLOTS_PER_EPOCH>(
+                &self.beacon,
+                &ExecutionPayloadHeader::from(&self.execution),
+                &self.execution_branch,
+                fork_schedule,
+            );
+        }
+        verify_execution_payload::<_, SLOTS_PER_EPOCH>(
+            &self.beacon,
+            &self.execution,
+            &self.execution_branch,
+            fork_schedule,
+        )
+    }
+}
+
+#[derive(Clone, Default, Debug, Eq, PartialEq, SimpleSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LightClientBootstrapDeneb<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const CURRENT_SYNC_COMMITTEE_GINDEX: usize,
+    const CURRENT_SYNC_COMMITTEE_PROOF_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> {
+    pub header: LightClientHeaderDeneb<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub current_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
+    pub current_sync_committee_branch: Vector<Node, CURRENT_SYNC_COMMITTEE_PROOF_SIZE>,
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const CURRENT_SYNC_COMMITTEE_GINDEX: usize,
+        const CURRENT_SYNC_COMMITTEE_PROOF_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    > ForkBootstrap
+    for LightClientBootstrapDeneb<
+        SYNC_COMMITTEE_SIZE,
+        CURRENT_SYNC_COMMITTEE_GINDEX,
+        CURRENT_SYNC_COMMITTEE_PROOF_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >
+{
+    type Header = LightClientHeaderDeneb<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>;
+    type SyncCommittee = SyncCommittee<SYNC_COMMITTEE_SIZE>;
+
+    fn header(&self) -> &Self::Header {
+        &self.header
+    }
+
+    fn current_sync_committee(&self) -> ProvenField<'_, Self::SyncCommittee, Node> {
+        ProvenField {
+            value: &self.current_sync_committee,
+            branch: &self.current_sync_committee_branch,
+            gindex: CURRENT_SYNC_COMMITTEE_GINDEX,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug, Eq, PartialEq, SimpleSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LightClientUpdateDeneb<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const NEXT_SYNC_COMMITTEE_GINDEX: usize,
+    const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize,
+    const FINALIZED_ROOT_GINDEX: usize,
+    const FINALIZED_ROOT_PROOF_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> {
+    pub attested_header: LightClientHeaderDeneb<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub next_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
+    pub next_sync_committee_branch: Vector<Node, NEXT_SYNC_COMMITTEE_PROOF_SIZE>,
+    pub finalized_header: LightClientHeaderDeneb<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub finality_branch: Vector<Node, FINALIZED_ROOT_PROOF_SIZE>,
+    pub sync_aggregate: SyncAggregate<SYNC_COMMITTEE_SIZE>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::as_str"))]
+    pub signature_slot: Slot,
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const NEXT_SYNC_COMMITTEE_GINDEX: usize,
+        const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize,
+        const FINALIZED_ROOT_GINDEX: usize,
+        const FINALIZED_ROOT_PROOF_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    > ForkUpdate
+    for LightClientUpdateDeneb<
+        SYNC_COMMITTEE_SIZE,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+        FINALIZED_ROOT_GINDEX,
+        FINALIZED_ROOT_PROOF_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >
+{
+    type Header = LightClientHeaderDeneb<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>;
+    type SyncCommittee = SyncCommittee<SYNC_COMMITTEE_SIZE>;
+    type BranchNode = Node;
+
+    fn attested_header(&self) -> &Self::Header {
+        &self.attested_header
+    }
+
+    fn sync_aggregate(&self) -> &SyncAggregate<SYNC_COMMITTEE_SIZE> {
+        &self.sync_aggregate
+    }
+
+    fn signature_slot(&self) -> Slot {
+        self.signature_slot
+    }
+
+    fn next_sync_committee(&self) -> Option<ProvenField<'_, Self::SyncCommittee, Node>> {
+        Some(ProvenField {
+            value: &self.next_sync_committee,
+            branch: &self.next_sync_committee_branch,
+            gindex: NEXT_SYNC_COMMITTEE_GINDEX,
+        })
+    }
+
+    fn finalized_header(&self) -> Option<ProvenField<'_, Self::Header, Node>> {
+        Some(ProvenField {
+            value: &self.finalized_header,
+            branch: &self.finality_branch,
+            gindex: FINALIZED_ROOT_GINDEX,
+        })
+    }
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Clone, SimpleSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LightClientFinalityUpdateDeneb<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const FINALIZED_ROOT_GINDEX: usize,
+    const FINALIZED_ROOT_PROOF_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> {
+    pub attested_header: LightClientHeaderDeneb<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub finalized_header: LightClientHeaderDeneb<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub finality_branch: Vector<Bytes32, FINALIZED_ROOT_PROOF_SIZE>,
+    pub sync_aggregate: SyncAggregate<SYNC_COMMITTEE_SIZE>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::as_str"))]
+    pub signature_slot: Slot,
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const FINALIZED_ROOT_GINDEX: usize,
+        const FINALIZED_ROOT_PROOF_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    > ForkUpdate
+    for LightClientFinalityUpdateDeneb<
+        SYNC_COMMITTEE_SIZE,
+        FINALIZED_ROOT_GINDEX,
+        FINALIZED_ROOT_PROOF_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >
+{
+    type Header = LightClientHeaderDeneb<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>;
+    type SyncCommittee = SyncCommittee<SYNC_COMMITTEE_SIZE>;
+    type BranchNode = Bytes32;
+
+    fn attested_header(&self) -> &Self::Header {
+        &self.attested_header
+    }
+
+    fn sync_aggregate(&self) -> &SyncAggregate<SYNC_COMMITTEE_SIZE> {
+        &self.sync_aggregate
+    }
+
+    fn signature_slot(&self) -> Slot {
+        self.signature_slot
+    }
+
+    fn finalized_header(&self) -> Option<ProvenField<'_, Self::Header, Bytes32>> {
+        Some(ProvenField {
+            value: &self.finalized_header,
+            branch: &self.finality_branch,
+            gindex: FINALIZED_ROOT_GINDEX,
+        })
+    }
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Clone, SimpleSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LightClientOptimisticUpdateDeneb<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> {
+    pub attested_header: LightClientHeaderDeneb<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub sync_aggregate: SyncAggregate<SYNC_COMMITTEE_SIZE>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::as_str"))]
+    pub signature_slot: Slot,
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    > ForkUpdate
+    for LightClientOptimisticUpdateDeneb<
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >
+{
+    type Header = LightClientHeaderDeneb<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>;
+    type SyncCommittee = SyncCommittee<SYNC_COMMITTEE_SIZE>;
+    type BranchNode = Node;
+
+    fn attested_header(&self) -> &Self::Header {
+        &self.attested_header
+    }
+
+    fn sync_aggregate(&self) -> &SyncAggregate<SYNC_COMMITTEE_SIZE> {
+        &self.sync_aggregate
+    }
+
+    fn signature_slot(&self) -> Slot {
+        self.signature_slot
+    }
+}
+
+/// Converts a Deneb finality update into a `LightClientUpdateDeneb` with `next_sync_committee` and its branch
+/// zeroed, keeping the execution payload headers.
+/// Fails with `InvalidFinalityBranch` if the finality branch cannot be carried over.
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const NEXT_SYNC_COMMITTEE_GINDEX: usize,
+        const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize,
+        const FINALIZED_ROOT_GINDEX: usize,
+        const FINALIZED_ROOT_PROOF_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    >
+    TryFrom<
+        &LightClientFinalityUpdateDeneb<
+            SYNC_COMMITTEE_SIZE,
+            FINALIZED_ROOT_GINDEX,
+            FINALIZED_ROOT_PROOF_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >,
+    >
+    for LightClientUpdateDeneb<
+        SYNC_COMMITTEE_SIZE,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+        FINALIZED_ROOT_GINDEX,
+        FINALIZED_ROOT_PROOF_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >
+{
+    type Error = VerificationError;
+
+    fn try_from(
+        update: &LightClientFinalityUpdateDeneb<
+            SYNC_COMMITTEE_SIZE,
+            FINALIZED_ROOT_GINDEX,
+            FINALIZED_ROOT_PROOF_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            attested_header: update.attested_header.clone(),
+            next_sync_committee: SyncCommittee::default(),
+            next_sync_committee_branch: Vector::default(),
+            finalized_header: update.finalized_header.clone(),
+            finality_branch: finality_branch_nodes(
+                &update.finality_branch,
+                &update.finalized_header.beacon,
+                &update.attested_header.beacon,
+            )?,
+            sync_aggregate: update.sync_aggregate.clone(),
+            signature_slot: update.signature_slot,
+        })
+    }
+}
+
+/// Converts a Deneb optimistic update into a `LightClientUpdateDeneb` with the next sync committee, the
+/// finalized header and their branches zeroed, keeping the execution payload header.
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const NEXT_SYNC_COMMITTEE_GINDEX: usize,
+        const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize,
+        const FINALIZED_ROOT_GINDEX: usize,
+        const FINALIZED_ROOT_PROOF_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    >
+    From<
+        &LightClientOptimisticUpdateDeneb<
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >,
+    >
+    for LightClientUpdateDeneb<
+        SYNC_COMMITTEE_SIZE,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+        FINALIZED_ROOT_GINDEX,
+        FINALIZED_ROOT_PROOF_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >
+{
+    fn from(
+        update: &LightClientOptimisticUpdateDeneb<
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >,
+    ) -> Self {
+        Self {
+            attested_header: update.attested_header.clone(),
+            sync_aggregate: update.sync_aggregate.clone(),
+            signature_slot: update.signature_slot,
+            ..Default::default()
+        }
+    }
+}
+
+/// Generalized indices into the Electra `BeaconState`. It has more than 32 fields, so
+/// `finalized_checkpoint` and the sync committees sit one level deeper than in earlier forks.
+pub const FINALIZED_ROOT_GINDEX_ELECTRA: usize = 169;
+pub const FINALIZED_ROOT_PROOF_SIZE_ELECTRA: usize = 7;
+pub const CURRENT_SYNC_COMMITTEE_GINDEX_ELECTRA: usize = 86;
+pub const CURRENT_SYNC_COMMITTEE_PROOF_SIZE_ELECTRA: usize = 6;
+pub const NEXT_SYNC_COMMITTEE_GINDEX_ELECTRA: usize = 87;
+pub const NEXT_SYNC_COMMITTEE_PROOF_SIZE_ELECTRA: usize = 6;
+
+/// Electra keeps the Deneb `LightClientHeader`
+pub type LightClientHeaderElectra<
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> = LightClientHeaderDeneb<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>;
+
+pub type LightClientBootstrapElectra<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> = LightClientBootstrapDeneb<
+    SYNC_COMMITTEE_SIZE,
+    CURRENT_SYNC_COMMITTEE_GINDEX_ELECTRA,
+    CURRENT_SYNC_COMMITTEE_PROOF_SIZE_ELECTRA,
+    BYTES_PER_LOGS_BLOOM,
+    MAX_EXTRA_DATA_BYTES,
+>;
+
+pub type LightClientUpdateElectra<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> = LightClientUpdateDeneb<
+    SYNC_COMMITTEE_SIZE,
+    NEXT_SYNC_COMMITTEE_GINDEX_ELECTRA,
+    NEXT_SYNC_COMMITTEE_PROOF_SIZE_ELECTRA,
+    FINALIZED_ROOT_GINDEX_ELECTRA,
+    FINALIZED_ROOT_PROOF_SIZE_ELECTRA,
+    BYTES_PER_LOGS_BLOOM,
+    MAX_EXTRA_DATA_BYTES,
+>;
+
+pub type LightClientFinalityUpdateElectra<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> = LightClientFinalityUpdateDeneb<
+    SYNC_COMMITTEE_SIZE,
+    FINALIZED_ROOT_GINDEX_ELECTRA,
+    FINALIZED_ROOT_PROOF_SIZE_ELECTRA,
+    BYTES_PER_LOGS_BLOOM,
+    MAX_EXTRA_DATA_BYTES,
+>;
+
+pub type LightClientOptimisticUpdateElectra<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> = LightClientOptimisticUpdateDeneb<
+    SYNC_COMMITTEE_SIZE,
+    BYTES_PER_LOGS_BLOOM,
+    MAX_EXTRA_DATA_BYTES,
+>;
+
+/// The trusted header and latest sync committee reached by `LightClientStore::verify_update_chain`,
+/// with the header type of the verified updates
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct VerifiedUpdateChain<const SYNC_COMMITTEE_SIZE: usize, H = BeaconBlockHeader> {
+    /// Newest finalized header proven by the chain, or the bootstrap header if none was
+    pub finalized_header: H,
+    /// Last sync committee proven by the chain
+    pub sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
+    /// Sync committee period `sync_committee` is responsible for
+    pub sync_committee_period: u64,
+}
+
+/// Verification failure of the update at `index` of a chain of updates
+#[derive(Debug)]
+pub struct UpdateChainError {
+    /// Position of the failing update, `None` if the bootstrap the chain starts from failed
+    pub index: Option<usize>,
+    pub error: VerificationError,
+}
+
+/// The trusted state of a light client, advanced by processing updates of type `U`.
+///
+/// `U` defaults to the altair `LightClientUpdate`, whose headers are plain beacon block headers.
+/// A store of a later fork's update, e.g. `LightClientUpdateCapella`, keeps that fork's headers
+/// together with their proven execution payload headers.
+///
+/// See https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#lightclientstore
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct LightClientStore<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const NEXT_SYNC_COMMITTEE_GINDEX: usize,
+    const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize,
+    const FINALIZED_ROOT_GINDEX: usize,
+    const FINALIZED_ROOT_PROOF_SIZE: usize,
+    const SLOTS_PER_EPOCH: usize,
+    const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize,
+    U: ForkUpdate = LightClientUpdate<
+        SYNC_COMMITTEE_SIZE,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+        FINALIZED_ROOT_GINDEX,
+        FINALIZED_ROOT_PROOF_SIZE,
+    >,
+> {
+    /// Header that is finalized
+    pub finalized_header: U::Header,
+    /// Sync committee for the period of `finalized_header`
+    pub current_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
+    /// Sync committee for the period after `finalized_header`, default if not yet known
+    pub next_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
+    /// Best available update to force-apply if finality is not reached within `UPDATE_TIMEOUT`
+    pub best_valid_update: Option<U>,
+    /// Most recent available reasonably-safe header
+    pub optimistic_header: U::Header,
+    /// Max number of active participants in a sync committee during the previous `UPDATE_TIMEOUT` window
+    pub previous_max_active_participants: usize,
+    /// Max number of active participants in a sync committee during the current `UPDATE_TIMEOUT` window
+    pub current_max_active_participants: usize,
+    /// Index of the current `UPDATE_TIMEOUT` window, i.e. `current_slot / UPDATE_TIMEOUT`
+    pub participation_window: u64,
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const NEXT_SYNC_COMMITTEE_GINDEX: usize,
+        const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize,
+        const FINALIZED_ROOT_GINDEX: usize,
+        const FINALIZED_ROOT_PROOF_SIZE: usize,
+        const SLOTS_PER_EPOCH: usize,
+        const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize,
+        U,
+    >
+    LightClientStore<
+        SYNC_COMMITTEE_SIZE,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+        FINALIZED_ROOT_GINDEX,
+        FINALIZED_ROOT_PROOF_SIZE,
+        SLOTS_PER_EPOCH,
+        EPOCHS_PER_SYNC_COMMITTEE_PERIOD,
+        U,
+    >
+where
+    U: ForkUpdate<SyncCommittee = SyncCommittee<SYNC_COMMITTEE_SIZE>> + Clone,
+{
+    /// Number of slots after which the best valid update is force-applied in the absence of finality
+    pub const UPDATE_TIMEOUT: Slot = (SLOTS_PER_EPOCH * EPOCHS_PER_SYNC_COMMITTEE_PERIOD) as Slot;
+
+    /// Create a store from a header and the sync committee for its period. Both must already be trusted.
+    pub fn new(
+        finalized_header: U::Header,
+        current_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
+    ) -> Self {
+        Self {
+            optimistic_header: finalized_header.clone(),
+            finalized_header,
+            current_sync_committee,
+            next_sync_committee: SyncCommittee::default(),
+            best_valid_update: None,
+            previous_max_active_participants: 0,
+            current_max_active_participants: 0,
+            participation_window: 0,
+        }
+    }
+
+    /// Initialize a store from a bootstrap that is verified against a trusted block root.
+    ///
+    /// See https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#initialize_light_client_store
+    pub fn initialize<B>(
+        trusted_block_root: Root,
+        bootstrap: &B,
+        fork_schedule: &ForkSchedule,
+    ) -> Result<Self, VerificationError>
+    where
+        B: ForkBootstrap<SyncCommittee = SyncCommittee<SYNC_COMMITTEE_SIZE>>,
+        B::Header: Into<U::Header>,
+    {
+        bootstrap.verify::<SLOTS_PER_EPOCH>(trusted_block_root, fork_schedule)?;
+        Ok(Self::new(
+            bootstrap.header().clone().into(),
+            bootstrap.current_sync_committee().value.clone(),
+        ))
+    }
+
+    /// Verify an ordered sequence of updates, one per sync committee period, starting from a bootstrap
+    /// that is verified against a trusted block root first.
+    ///
+    /// Every update must be signed by the committee proven by the bootstrap or the previous update
+    /// and must prove the committee of the following period, which is then used for the next update.
+    pub fn verify_update_chain<B, V>(
+        trusted_block_root: Root,
+        bootstrap: &B,
+        updates: &[V],
+        fork_schedule: &ForkSchedule,
+    ) -> Result<VerifiedUpdateChain<SYNC_COMMITTEE_SIZE, V::Header>, UpdateChainError>
+    where
+        B: ForkBootstrap<SyncCommittee = SyncCommittee<SYNC_COMMITTEE_SIZE>>,
+        B::Header: Into<V::Header>,
+        V: ForkUpdate<SyncCommittee = SyncCommittee<SYNC_COMMITTEE_SIZE>>,
+    {
+        bootstrap
+            .verify::<SLOTS_PER_EPOCH>(trusted_block_root, fork_schedule)
+            .map_err(|error| UpdateChainError { index: None, error })?;
+        let mut chain = VerifiedUpdateChain {
+            finalized_header: bootstrap.header().clone().into(),
+            sync_committee: bootstrap.current_sync_committee().value.clone(),
+            sync_committee_period: Self::sync_committee_period_at_slot(
+                bootstrap.header().beacon().slot,
+            ),
+        };
+
+        for (index, update) in updates.iter().enumerate() {
+            let verify = |chain: &VerifiedUpdateChain<SYNC_COMMITTEE_SIZE, V::Header>| {
+                let signature_period =
+                    update.signature_period::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>();
+                if signature_period != chain.sync_committee_period {
+                    return Err(VerificationError::UnknownSyncCommittee {
+                        period: signature_period,
+                        store_period: chain.sync_committee_period,
+                    });
+                }
+                if !update
+                    .has_relevant_sync_committee::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>()
+                {
+                    return Err(VerificationError::MissingNextSyncCommittee {
+                        attested_slot: update.attested_header().beacon().slot,
+                    });
+                }
+                // checks the slot order as well
+                update.verify::<SLOTS_PER_EPOCH>(fork_schedule, &chain.sync_committee)
+            };
+            verify(&chain).map_err(|error| UpdateChainError {
+                index: Some(index),
+                error,
+            })?;
+
+            if let Some(proof) = update.next_sync_committee() {
+                chain.sync_committee = proof.value.clone();
+            }
+            chain.sync_committee_period += 1;
+            if let Some(proof) = update.finalized_header() {
+                if update.is_finality_update()
+                    && proof.value.beacon().slot > chain.finalized_header.beacon().slot
+                {
+                    chain.finalized_header = proof.value.clone();
+                }
+            }
+        }
+
+        Ok(chain)
+    }
+
+    fn sync_committee_period_at_slot(slot: Slot) -> u64 {
+        compute_sync_committee_period_at_slot::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>(
+            slot,
+        )
+    }
+
+    /// Reject a store whose `finalized_header` is too old to resume syncing from at `current_slot`
+    pub fn verify_weak_subjectivity(
+        &self,
+        current_slot: Slot,
+        ws_period: Epoch,
+    ) -> Result<(), VerificationError> {
+        verify_weak_subjectivity::<SLOTS_PER_EPOCH>(
+            self.finalized_header.beacon().slot,
+            current_slot,
+            ws_period,
+        )
+    }
+
+    /// Whether the sync committee for the period after `finalized_header` is known
+    pub fn is_next_sync_committee_known(&self) -> bool {
+        self.next_sync_committee != SyncCommittee::default()
+    }
+
+    /// Check an update against the store, selecting the sync committee that must have signed it
+    /// from the period of its `signature_slot`.
+    ///
+    /// See https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#validate_light_client_update
+    pub fn validate_light_client_update(
+        &self,
+        update: &U,
+        current_slot: Slot,
+        fork_schedule: &ForkSchedule,
+    ) -> Result<(), VerificationError> {
+        update.validate_slots(current_slot)?;
+
+        let finalized_slot = self.finalized_header.beacon().slot;
+        let store_period = Self::sync_committee_period_at_slot(finalized_slot);
+        let signature_period =
+            update.signature_period::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>();
+        let sync_committee = if signature_period == store_period {
+            &self.current_sync_committee
+        } else if signature_period == store_period + 1 && self.is_next_sync_committee_known() {
+            &self.next_sync_committee
+        } else {
+            return Err(VerificationError::UnknownSyncCommittee {
+                period: signature_period,
+                store_period,
+            });
+        };
+
+        // the update must either advance the attested header or provide the missing next sync committee
+        let attested_slot = update.attested_header().beacon().slot;
+        let attested_period =
+            update.attested_period::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>();
+        let update_has_next_sync_committee = !self.is_next_sync_committee_known()
+            && update.is_sync_committee_update()
+            && attested_period == store_period;
+        if attested_slot <= finalized_slot && !update_has_next_sync_committee {
+            return Err(VerificationError::StaleUpdate {
+                attested_slot,
+                finalized_slot,
+            });
+        }
+
+        update.verify_proofs::<SLOTS_PER_EPOCH>(fork_schedule)?;
+        // a next sync committee already known for the attested period must not change
+        if update.is_sync_committee_update()
+            && attested_period == store_period
+            && self.is_next_sync_committee_known()
+            && update
+                .next_sync_committee()
+                .is_some_and(|proof| *proof.value != self.next_sync_committee)
+        {
+            return Err(VerificationError::NextSyncCommitteeMismatch);
+        }
+
+        update.verify_signature::<SLOTS_PER_EPOCH>(fork_schedule, sync_committee)
+    }
+
+    /// Validate an update and advance the store with it.
+    ///
+    /// Valid updates that do not reach a 2/3 supermajority, or that do not
+    /// advance finality, are kept as `best_valid_update` so they can be
+    /// force-applied once `UPDATE_TIMEOUT` has elapsed.
+    pub fn process_light_client_update(
+        &mut self,
+        update: &U,
+        current_slot: Slot,
+        fork_schedule: &ForkSchedule,
+    ) -> Result<(), VerificationError> {
+        self.process_slot_for_light_client_store(current_slot);
+        self.validate_light_client_update(update, current_slot, fork_schedule)?;
+
+        let participants = update.num_participants();
+
+        // keep the best update in case it has to be force-applied
+        let is_better_update = match &self.best_valid_update {
+            None => true,
+            Some(best) => {
+                update.is_better_update::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>(best)
+            }
+        };
+        if is_better_update {
+            self.best_valid_update = Some(update.clone());
+        }
+
+        let advances_optimistic_header = self
+            .advances_optimistic_header(update.attested_header().beacon(), update.sync_aggregate());
+        self.current_max_active_participants =
+            self.current_max_active_participants.max(participants);
+        if advances_optimistic_header {
+            self.optimistic_header = update.attested_header().clone();
+        }
+
+        let update_has_finalized_next_sync_committee = !self.is_next_sync_committee_known()
+            && update.is_sync_committee_update()
+            && update.is_finality_update()
+            && Self::sync_committee_period_at_slot(update.finalized_slot())
+                == update.attested_period::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>();
+
+        if update.has_supermajority()
+            && (update.finalized_slot() > self.finalized_header.beacon().slot
+                || update_has_finalized_next_sync_committee)
+        {
+            self.apply_light_client_update(update)?;
+            self.best_valid_update = None;
+        }
+
+        Ok(())
+    }
+
+    /// Validate an optimistic update of any fork whose headers the store keeps and advance the
+    /// optimistic header with it if enough of the sync committee signed it.
+    pub fn process_light_client_optimistic_update<O>(
+        &mut self,
+        update: &O,
+        current_slot: Slot,
+        fork_schedule: &ForkSchedule,
+    ) -> Result<(), VerificationError>
+    where
+        O: ForkUpdate,
+        for<'a> U: From<&'a O>,
+    {
+        // the execution payload is dropped when converting into an update of an earlier fork
+        update
+            .attested_header()
+            .verify_execution_branch::<SLOTS_PER_EPOCH>(fork_schedule)?;
+        self.process_light_client_update(&U::from(update), current_slot, fork_schedule)
+    }
+
+    /// Validate a finality update of any fork whose headers the store keeps and advance the store with it.
+    /// As it carries no next sync committee it can only move the finalized and optimistic headers.
+    pub fn process_light_client_finality_update<F>(
+        &mut self,
+        update: &F,
+        current_slot: Slot,
+        fork_schedule: &ForkSchedule,
+    ) -> Result<(), VerificationError>
+    where
+        F: ForkUpdate,
+        for<'a> U: TryFrom<&'a F, Error = VerificationError>,
+    {
+        // the execution payloads are dropped when converting into an update of an earlier fork
+        update
+            .attested_header()
+            .verify_execution_branch::<SLOTS_PER_EPOCH>(fork_schedule)?;
+        if let Some(proof) = update.finalized_header() {
+            if *proof.value != F::Header::default() {
+                proof
+                    .value
+                    .verify_execution_branch::<SLOTS_PER_EPOCH>(fork_schedule)?;
+            }
+        }
+        self.process_light_client_update(&U::try_from(update)?, current_slot, fork_schedule)
+    }
+
+    /// Move the store forward to the `finalized_header` of an already validated update,
+    /// rotating the sync committees if it crosses into the next sync committee period.
+    pub fn apply_light_client_update(&mut self, update: &U) -> Result<(), VerificationError> {
+        let finalized_header = update
+            .finalized_header()
+            .map(|proof| proof.value.clone())
+            .unwrap_or_default();
+        self.apply_finalized_header(finalized_header, Self::update_next_sync_committee(update))
+    }
+
+    /// The next sync committee carried by `update`, default if it carries none
+    fn update_next_sync_committee(update: &U) -> SyncCommittee<SYNC_COMMITTEE_SIZE> {
+        update
+            .next_sync_committee()
+            .map(|proof| proof.value.clone())
+            .unwrap_or_default()
+    }
+
+    fn apply_finalized_header(
+        &mut self,
+        finalized_header: U::Header,
+        next_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
+    ) -> Result<(), VerificationError> {
+        let store_period = Self::sync_committee_period_at_slot(self.finalized_header.beacon().slot);
+        let update_finalized_period =
+            Self::sync_committee_period_at_slot(finalized_header.beacon().slot);
+
+        if !self.is_next_sync_committee_known() {
+            if update_finalized_period != store_period {
+                return Err(VerificationError::UnknownSyncCommittee {
+                    period: update_finalized_period,
+                    store_period,
+                });
+            }
+            self.next_sync_committee = next_sync_committee;
+        } else if update_finalized_period == store_period + 1 {
+            self.current_sync_committee =
+                core::mem::replace(&mut self.next_sync_committee, next_sync_committee);
+        }
+
+        if finalized_header.beacon().slot > self.finalized_header.beacon().slot {
+            self.finalized_header = finalized_header;
+            if self.finalized_header.beacon().slot > self.optimistic_header.beacon().slot {
+                self.optimistic_header = self.finalized_header.clone();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Rotate the max active participant counters when `current_slot` enters a new `UPDATE_TIMEOUT` window.
+    /// The counters of windows without any processed update count as zero.
+    pub fn process_slot_for_light_client_store(&mut self, current_slot: Slot) {
+        let window = current_slot / Self::UPDATE_TIMEOUT;
+        if window <= self.participation_window {
+            return;
+        }
+        self.previous_max_active_participants = if window == self.participation_window + 1 {
+            self.current_max_active_participants
+        } else {
+            0
+        };
+        self.current_max_active_participants = 0;
+        self.participation_window = window;
+    }
+
+    /// Number of participants an update needs to exceed to advance the optimistic header
+    ///
+    /// See https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#get_safety_threshold
+    pub fn get_safety_threshold(&self) -> usize {
+        self.previous_max_active_participants
+            .max(self.current_max_active_participants)
+            / 2
+    }
+
+    /// Whether a valid update of `attested_header` signed with `sync_aggregate` would become the new
+    /// optimistic header. The update's own participation counts towards the safety threshold.
+    pub fn advances_optimistic_header(
+        &self,
+        attested_header: &BeaconBlockHeader,
+        sync_aggregate: &SyncAggregate<SYNC_COMMITTEE_SIZE>,
+    ) -> bool {
+        let participants = sync_aggregate.num_participants();
+        let safety_threshold = self
+            .previous_max_active_participants
+            .max(self.current_max_active_participants)
+            .max(participants)
+            / 2;
+        participants > safety_threshold
+            && attested_header.slot > self.optimistic_header.beacon().slot
+    }
+
+    /// Force-apply `best_valid_update` if no finality has been reached within `UPDATE_TIMEOUT`.
+    ///
+    /// Should be called once per slot.
+    pub fn process_light_client_store_force_update(
+        &mut self,
+        current_slot: Slot,
+    ) -> Result<(), VerificationError> {
+        self.process_slot_for_light_client_store(current_slot);
+        let finalized_slot = self.finalized_header.beacon().slot;
+        if current_slot <= finalized_slot + Self::UPDATE_TIMEOUT {
+            return Ok(());
+        }
+        let Some(update) = &self.best_valid_update else {
+            return Ok(());
+        };
+        // during extended non-finality the attested header is treated as finalized so the store can
+        // still progress into later sync committee periods
+        let finalized_header = match update.finalized_header() {
+            Some(proof) if proof.value.beacon().slot > finalized_slot => proof.value.clone(),
+            _ => update.attested_header().clone(),
+        };
+        let next_sync_committee = Self::update_next_sync_committee(update);
+        // the update is kept if it cannot be applied, so forcing can be retried
+        self.apply_finalized_header(finalized_header, next_sync_committee)?;
+        self.best_valid_update = None;
+        Ok(())
+    }
+}
+
+/// A light client store of any preset, so code generic over a `Spec` can initialize and advance it
+pub trait AnyLightClientStore: Clone + Debug + Default + PartialEq {
+    type SyncCommittee: AnySyncCommittee;
+    type Update: ForkUpdate<SyncCommittee = Self::SyncCommittee>;
+
+    fn finalized_header(&self) -> &<Self::Update as ForkUpdate>::Header;
+
+    fn optimistic_header(&self) -> &<Self::Update as ForkUpdate>::Header;
+
+    /// Initialize a store from a bootstrap of any fork whose header the store keeps,
+    /// verified against a trusted block root
+    fn initialize<B>(
+        trusted_block_root: Root,
+        bootstrap: &B,
+        fork_schedule: &ForkSchedule,
+    ) -> Result<Self, VerificationError>
+    where
+        B: ForkBootstrap<SyncCommittee = Self::SyncCommittee>,
+        B::Header: Into<<Self::Update as ForkUpdate>::Header>;
+
+    fn validate_light_client_update(
+        &self,
+        update: &Self::Update,
+        current_slot: Slot,
+        fork_schedule: &ForkSchedule,
+    ) -> Result<(), VerificationError>;
+
+    fn process_light_client_update(
+        &mut self,
+        update: &Self::Update,
+        current_slot: Slot,
+        fork_schedule: &ForkSchedule,
+    ) -> Result<(), VerificationError>;
+
+    fn process_light_client_store_force_update(
+        &mut self,
+        current_slot: Slot,
+    ) -> Result<(), VerificationError>;
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const NEXT_SYNC_COMMITTEE_GINDEX: usize,
+        const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize,
+        const FINALIZED_ROOT_GINDEX: usize,
+        const FINALIZED_ROOT_PROOF_SIZE: usize,
+        const SLOTS_PER_EPOCH: usize,
+        const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize,
+        U,
+    > AnyLightClientStore
+    for LightClientStore<
+        SYNC_COMMITTEE_SIZE,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+        FINALIZED_ROOT_GINDEX,
+        FINALIZED_ROOT_PROOF_SIZE,
+        SLOTS_PER_EPOCH,
+        EPOCHS_PER_SYNC_COMMITTEE_PERIOD,
+        U,
+    >
+where
+    U: ForkUpdate<SyncCommittee = SyncCommittee<SYNC_COMMITTEE_SIZE>>
+        + Clone
+        + Debug
+        + Default
+        + PartialEq,
+{
+    type SyncCommittee = SyncCommittee<SYNC_COMMITTEE_SIZE>;
+    type Update = U;
+
+    fn finalized_header(&self) -> &U::Header {
+        &self.finalized_header
+    }
+
+    fn optimistic_header(&self) -> &U::Header {
+        &self.optimistic_header
+    }
+
+    fn initialize<B>(
+        trusted_block_root: Root,
+        bootstrap: &B,
+        fork_schedule: &ForkSchedule,
+    ) -> Result<Self, VerificationError>
+    where
+        B: ForkBootstrap<SyncCommittee = Self::SyncCommittee>,
+        B::Header: Into<U::Header>,
+    {
+        Self::initialize(trusted_block_root, bootstrap, fork_schedule)
+    }
+
+    fn validate_light_client_update(
+        &self,
+        update: &U,
+        current_slot: Slot,
+        fork_schedule: &ForkSchedule,
+    ) -> Result<(), VerificationError> {
+        Self::validate_light_client_update(self, update, current_slot, fork_schedule)
+    }
+
+    fn process_light_client_update(
+        &mut self,
+        update: &U,
+        current_slot: Slot,
+        fork_schedule: &ForkSchedule,
+    ) -> Result<(), VerificationError> {
+        Self::process_light_client_update(self, update, current_slot, fork_schedule)
+    }
+
+    fn process_light_client_store_force_update(
+        &mut self,
+        current_slot: Slot,
+    ) -> Result<(), VerificationError> {
+        Self::process_light_client_store_force_update(self, current_slot)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::presets::minimal;
+    use crate::test_utils::{bytes32_branch, merkle_tree, TestCommittee};
+
+    const SLOTS_PER_EPOCH: usize = minimal::SLOTS_PER_EPOCH;
+    const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize = minimal::EPOCHS_PER_SYNC_COMMITTEE_PERIOD;
+
+    type Committee = TestCommittee<{ minimal::SYNC_COMMITTEE_SIZE }>;
+
+    /// A schedule without execution payloads, so headers need no execution branch
+    fn altair_fork_schedule() -> ForkSchedule {
+        ForkSchedule {
+            capella_fork_epoch: Epoch::MAX,
+            deneb_fork_epoch: Epoch::MAX,
+            ..ForkSchedule::default()
+        }
+    }
+
+    /// An update of `attested_slot` signed by `signer` in the following slot that proves
+    /// `next_committee` and `finalized_header` if given
+    fn signed_update(
+        signer: &Committee,
+        attested_slot: Slot,
+        next_committee: Option<&Committee>,
+        finalized_header: Option<&BeaconBlockHeader>,
+        fork_schedule: &ForkSchedule,
+    ) -> minimal::LightClientUpdate {
+        let mut update = minimal::LightClientUpdate::default();
+        let mut leaves = vec![];
+        if let Some(next_committee) = next_committee {
+            update.next_sync_committee = next_committee.committee.clone();
+            let root = next_committee.committee.clone().hash_tree_root().unwrap();
+            leaves.push((minimal::NEXT_SYNC_COMMITTEE_GINDEX, root));
+        }
+        if let Some(finalized_header) = finalized_header {
+            update.finalized_header = finalized_header.clone();
+            let root = finalized_header.clone().hash_tree_root().unwrap();
+            leaves.push((minimal::FINALIZED_ROOT_GINDEX, root));
+        }
+        let (state_root, branches) = merkle_tree(&leaves);
+        for ((gindex, _), branch) in leaves.iter().zip(branches) {
+            if *gindex == minimal::NEXT_SYNC_COMMITTEE_GINDEX {
+                update.next_sync_committee_branch = Vector::try_from(branch).unwrap();
+            } else {
+                update.finality_branch = Vector::try_from(branch).unwrap();
+            }
+        }
+        update.attested_header = BeaconBlockHeader {
+            slot: attested_slot,
+            state_root,
+            ..Default::default()
+        };
+        update.signature_slot = attested_slot + 1;
+        update.sync_aggregate = signer.sign::<SLOTS_PER_EPOCH>(
+            &update.attested_header,
+            update.signature_slot,
+            fork_schedule,
+        );
+        update
+    }
+
+    /// The Capella form of `update`, whose headers carry no execution payload
+    fn capella_update(update: &minimal::LightClientUpdate) -> minimal::LightClientUpdateCapella {
+        minimal::LightClientUpdateCapella {
+            attested_header: minimal::LightClientHeader {
+                beacon: update.attested_header.clone(),
+                ..Default::default()
+            },
+            next_sync_committee: update.next_sync_committee.clone(),
+            next_sync_committee_branch: update.next_sync_committee_branch.clone(),
+            finalized_header: minimal::LightClientHeader {
+                beacon: update.finalized_header.clone(),
+                ..Default::default()
+            },
+            finality_branch: update.finality_branch.clone(),
+            sync_aggregate: update.sync_aggregate.clone(),
+            signature_slot: update.signature_slot,
+        }
+    }
+
+    fn header_at(slot: Slot) -> BeaconBlockHeader {
+        BeaconBlockHeader {
+            slot,
+            ..Default::default()
+        }
+    }
+
+    /// A bootstrap at `slot` proving `committee`, together with its block root
+    fn bootstrap_at(slot: Slot, committee: &Committee) -> (minimal::LightClientBootstrap, Root) {
+        let committee_root = committee.committee.clone().hash_tree_root().unwrap();
+        let (state_root, branches) =
+            merkle_tree(&[(minimal::CURRENT_SYNC_COMMITTEE_GINDEX, committee_root)]);
+        let mut bootstrap = minimal::LightClientBootstrap::default();
+        bootstrap.header.beacon = BeaconBlockHeader {
+            slot,
+            state_root,
+            ..Default::default()
+        };
+        bootstrap.current_sync_committee = committee.committee.clone();
+        bootstrap.current_sync_committee_branch = Vector::try_from(branches[0].clone()).unwrap();
+        let block_root = bootstrap.header.beacon.clone().hash_tree_root().unwrap();
+        (bootstrap, block_root)
+    }
+
+    /// A store finalized at `slot` that trusts `committee` for the period of `slot`
+    fn store_at(slot: Slot, committee: &Committee) -> minimal::LightClientStore {
+        minimal::LightClientStore::new(
+            BeaconBlockHeader {
+                slot,
+                ..Default::default()
+            },
+            committee.committee.clone(),
+        )
+    }
+
+    fn update_with_participants(participants: usize) -> minimal::LightClientUpdate {
+        let mut update = minimal::LightClientUpdate::default();
+        for i in 0..participants {
+            update.sync_aggregate.sync_committee_bits.set(i, true);
+        }
+        update.attested_header.slot = 10;
+        update.signature_slot = 11;
+        update
+    }
+
+    fn is_better(new: &minimal::LightClientUpdate, old: &minimal::LightClientUpdate) -> bool {
+        new.is_better_update::<SLOTS_PER_EPOCH, EPOCHS_PER_SYNC_COMMITTEE_PERIOD>(old)
+    }
+
+    #[test]
+    fn test_is_better_update() {
+        let non_zero = Node::try_from(&[1u8; 32][..]).unwrap();
+
+        // supermajority beats finality
+        let mut minority = update_with_participants(21);
+        minority.finality_branch[0] = non_zero;
+        let supermajority = update_with_participants(22);
+        assert!(is_better(&supermajority, &minority));
+        assert!(!is_better(&minority, &supermajority));
+
+        // below supermajority participation decides
+        assert!(is_better(
+            &update_with_participants(5),
+            &update_with_participants(4)
+        ));
+
+        // a relevant sync committee beats finality
+        let mut with_committee = update_with_participants(22);
+        with_committee.next_sync_committee_branch[0] = non_zero;
+        let mut with_finality = update_with_participants(32);
+        with_finality.finality_branch[0] = non_zero;
+        assert!(is_better(&with_committee, &with_finality));
+
+        // a committee signed in the next period is not relevant
+        let mut irrelevant = with_committee.clone();
+        irrelevant.signature_slot = (SLOTS_PER_EPOCH * EPOCHS_PER_SYNC_COMMITTEE_PERIOD) as Slot;
+        assert!(is_better(&with_finality, &irrelevant));
+
+        // with a supermajority finality beats extra participation
+        assert!(is_better(&with_finality, &update_with_participants(32)));
+        assert!(is_better(
+            &update_with_participants(32),
+            &update_with_participants(22)
+        ));
+
+        // older data wins ties
+        let mut newer = update_with_participants(22);
+        newer.attested_header.slot = 12;
+        newer.signature_slot = 13;
+        assert!(is_better(&update_with_participants(22), &newer));
+        assert!(!is_better(&newer, &newer.clone()));
+    }
+
+    #[test]
+    fn test_verify_update_chain() {
+        let fork_schedule = altair_fork_schedule();
+        let [first, second, third] = [1, 2, 3].map(Committee::new);
+        let (bootstrap, trusted_block_root) = bootstrap_at(70, &first);
+        let verify = |updates: &[minimal::LightClientUpdate]| {
+            minimal::LightClientStore::verify_update_chain(
+                trusted_block_root,
+                &bootstrap,
+                updates,
+                &fork_schedule,
+            )
+        };
+
+        let chain = verify(&[]).unwrap();
+        assert_eq!(chain.finalized_header, bootstrap.header.beacon);
+        assert_eq!(chain.sync_committee_period, 1);
+
+        // each period is signed by the committee proven in the period before
+        let updates = [
+            signed_update(
+                &first,
+                80,
+                Some(&second),
+                Some(&header_at(72)),
+                &fork_schedule,
+            ),
+            signed_update(
+                &second,
+                140,
+                Some(&third),
+                Some(&header_at(136)),
+                &fork_schedule,
+            ),
+        ];
+        let chain = verify(&updates).unwrap();
+        assert_eq!(chain.finalized_header, header_at(136));
+        assert_eq!(chain.sync_committee, third.committee);
+        assert_eq!(chain.sync_committee_period, 3);
+
+        // the bootstrap is verified before any update
+        let err = minimal::LightClientStore::verify_update_chain(
+            Root::default(),
+            &bootstrap,
+            &updates,
+            &fork_schedule,
+        )
+        .unwrap_err();
+        assert_eq!(err.index, None);
+        assert!(matches!(
+            err.error,
+            VerificationError::UntrustedBlockRoot { .. }
+        ));
+
+        // an update signed in period 0 cannot be verified by the committee of period 1
+        let err = verify(&[update_with_participants(32)]).unwrap_err();
+        assert_eq!(err.index, Some(0));
+        assert!(matches!(
+            err.error,
+            VerificationError::UnknownSyncCommittee {
+                period: 0,
+                store_period: 1
+            }
+        ));
+
+        // an update that does not carry the next sync committee breaks the chain
+        let without_committee = signed_update(&second, 140, None, None, &fork_schedule);
+        let err = verify(&[updates[0].clone(), without_committee]).unwrap_err();
+        assert_eq!(err.index, Some(1));
+        assert!(matches!(
+            err.error,
+            VerificationError::MissingNextSyncCommittee { attested_slot: 140 }
+        ));
+
+        // skipping a period breaks the chain
+        let err = verify(&updates[1..]).unwrap_err();
+        assert_eq!(err.index, Some(0));
+        assert!(matches!(
+            err.error,
+            VerificationError::UnknownSyncCommittee {
+                period: 2,
+                store_period: 1
+            }
+        ));
+
+        // a finalized header after the attested header breaks the chain
+        let unordered = signed_update(
+            &first,
+            80,
+            Some(&second),
+            Some(&header_at(84)),
+            &fork_schedule,
+        );
+        let err = verify(&[unordered]).unwrap_err();
+        assert_eq!(err.index, Some(0));
+        assert!(matches!(
+            err.error,
+            VerificationError::InvalidSlotOrder {
+                attested_slot: 80,
+                finalized_slot: 84,
+                ..
+            }
+        ));
+
+        // the updates of later forks are chained in the same way
+        let capella_updates: Vec<_> = updates.iter().map(capella_update).collect();
+        let chain = minimal::LightClientStore::verify_update_chain(
+            trusted_block_root,
+            &bootstrap,
+            &capella_updates,
+            &fork_schedule,
+        )
+        .unwrap();
+        assert_eq!(chain.finalized_header.beacon, header_at(136));
+        assert_eq!(chain.sync_committee, third.committee);
+    }
+
+    #[test]
+    fn test_safety_threshold() {
+        let mut store = minimal::LightClientStore::default();
+        let update_timeout = minimal::LightClientStore::UPDATE_TIMEOUT;
+        let advances = |store: &minimal::LightClientStore, participants| {
+            let update = update_with_participants(participants);
+            store.advances_optimistic_header(&update.attested_header, &update.sync_aggregate)
+        };
+
+        store.current_max_active_participants = 20;
+        assert_eq!(store.get_safety_threshold(), 10);
+        assert!(!advances(&store, 10));
+        assert!(advances(&store, 11));
+
+        // the counters rotate once per window
+        store.process_slot_for_light_client_store(update_timeout);
+        store.process_slot_for_light_client_store(update_timeout + 1);
+        assert_eq!(store.previous_max_active_participants, 20);
+        assert_eq!(store.current_max_active_participants, 0);
+        assert!(advances(&store, 11));
+
+        // an update raising the max must also exceed its own threshold
+        store.current_max_active_participants = 0;
+        store.previous_max_active_participants = 0;
+        assert!(advances(&store, 1));
+
+        // skipped windows reset both counters
+        store.current_max_active_participants = 32;
+        store.process_slot_for_light_client_store(4 * update_timeout);
+        assert_eq!(store.get_safety_threshold(), 0);
+    }
+
+    #[test]
+    fn test_validate_light_client_update_rejects_invalid_structure() {
+        let store = minimal::LightClientStore::default();
+        let fork_schedule = ForkSchedule::default();
+        let validate = |update: &minimal::LightClientUpdate, current_slot| {
+            store.validate_light_client_update(update, current_slot, &fork_schedule)
+        };
+
+        assert!(matches!(
+            validate(&update_with_participants(0), 11),
+            Err(VerificationError::InsufficientParticipation)
+        ));
+        assert!(matches!(
+            validate(&update_with_participants(1), 10),
+            Err(VerificationError::FutureSignatureSlot {
+                signature_slot: 11,
+                current_slot: 10
+            })
+        ));
+
+        let mut finalized_after_attested = update_with_participants(1);
+        finalized_after_attested.finalized_header.slot = 11;
+        assert!(matches!(
+            validate(&finalized_after_attested, 11),
+            Err(VerificationError::InvalidSlotOrder { .. })
+        ));
+
+        // a finalized header requires a finality branch
+        let mut unproven = update_with_participants(1);
+        unproven.finalized_header.slot = 8;
+        assert!(matches!(
+            validate(&unproven, 11),
+            Err(VerificationError::UnprovenFinalizedHeader)
+        ));
+    }
+
+    /// A block body root committing to `execution` and the branch proving it
+    fn prove_execution<P: Merkleized + Clone>(execution: &P) -> (Root, Vector<Bytes32, 4>) {
+        let execution_root = execution.clone().hash_tree_root().unwrap();
+        let (body_root, branches) = merkle_tree(&[(EXECUTION_PAYLOAD_GINDEX, execution_root)]);
+        let branch = Vector::try_from(bytes32_branch(&branches[0])).unwrap();
+        (body_root, branch)
+    }
+
+    #[test]
+    fn test_verify_execution_branch_across_forks() {
+        let fork_schedule = ForkSchedule {
+            capella_fork_epoch: 2,
+            deneb_fork_epoch: 3,
+            ..ForkSchedule::default()
+        };
+        let last_altair_slot = 2 * SLOTS_PER_EPOCH as Slot - 1;
+
+        // before Capella the execution payload must be empty and its branch zeroed
+        let mut header = minimal::LightClientHeader::default();
+        header.beacon.slot = last_altair_slot;
+        let verify = |header: &minimal::LightClientHeader| {
+            header.verify_execution_branch::<SLOTS_PER_EPOCH>(&fork_schedule)
+        };
+        assert!(verify(&header).is_ok());
+        header.execution.block_number = 1;
+        assert!(matches!(
+            verify(&header),
+            Err(VerificationError::UnexpectedExecutionPayload { slot }) if slot == last_altair_slot
+        ));
+        (header.beacon.body_root, header.execution_branch) = prove_execution(&header.execution);
+        assert!(matches!(
+            verify(&header),
+            Err(VerificationError::UnexpectedExecutionPayload { .. })
+        ));
+
+        // from Capella on it must be proven against the block body
+        header.beacon.slot += 1;
+        assert!(verify(&header).is_ok());
+        let mut unproven = header.clone();
+        unproven.execution.block_number = 2;
+        assert!(matches!(
+            verify(&unproven),
+            Err(VerificationError::InvalidExecutionBranch { .. })
+        ));
+        assert!(matches!(
+            verify(&minimal::LightClientHeader {
+                beacon: header.beacon.clone(),
+                ..Default::default()
+            }),
+            Err(VerificationError::InvalidExecutionBranch { .. })
+        ));
+
+        // before Deneb the block body commits to the Capella payload header, which has no blob gas
+        let mut header = minimal::LightClientHeaderDeneb::default();
+        header.beacon.slot = last_altair_slot + 1;
+        header.execution.block_number = 1;
+        let verify = |header: &minimal::LightClientHeaderDeneb| {
+            header.verify_execution_branch::<SLOTS_PER_EPOCH>(&fork_schedule)
+        };
+        (header.beacon.body_root, header.execution_branch) =
+            prove_execution(&minimal::ExecutionPayloadHeader::from(&header.execution));
+        assert!(verify(&header).is_ok());
+        let mut deneb_shaped = header.clone();
+        (deneb_shaped.beacon.body_root, deneb_shaped.execution_branch) =
+            prove_execution(&header.execution);
+        assert!(matches!(
+            verify(&deneb_shaped),
+            Err(VerificationError::InvalidExecutionBranch { .. })
+        ));
+        header.execution.excess_blob_gas = 1;
+        assert!(matches!(
+            verify(&header),
+            Err(VerificationError::UnexpectedExecutionPayload { .. })
+        ));
+
+        // from Deneb on it is proven as the Deneb payload header
+        header.beacon.slot += SLOTS_PER_EPOCH as Slot;
+        assert!(matches!(
+            verify(&header),
+            Err(VerificationError::InvalidExecutionBranch { .. })
+        ));
+        (header.beacon.body_root, header.execution_branch) = prove_execution(&header.execution);
+        assert!(verify(&header).is_ok());
+    }
+
+    #[test]
+    fn test_process_light_client_finality_update() {
+        let fork_schedule = altair_fork_schedule();
+        let committee = Committee::new(1);
+        let mut store = store_at(8, &committee);
+
+        let finalized_header = BeaconBlockHeader {
+            slot: 16,
+            proposer_index: 1,
+            ..Default::default()
+        };
+        let finalized_root = finalized_header.clone().hash_tree_root().unwrap();
+        let (state_root, branches) =
+            merkle_tree(&[(minimal::FINALIZED_ROOT_GINDEX, finalized_root)]);
+        let mut update = minimal::LightClientFinalityUpdate::default();
+        update.attested_header.beacon = BeaconBlockHeader {
+            slot: 24,
+            state_root,
+            ..Default::default()
+        };
+        update.finalized_header.beacon = finalized_header.clone();
+        update.finality_branch = Vector::try_from(bytes32_branch(&branches[0])).unwrap();
+        update.signature_slot = 25;
+        update.sync_aggregate =
+            committee.sign::<SLOTS_PER_EPOCH>(&update.attested_header.beacon, 25, &fork_schedule);
+
+        // a broken finality branch is rejected without touching the store
+        let mut broken = update.clone();
+        broken.finality_branch[0] = ByteVector(Vector::try_from(vec![1u8; 32]).unwrap());
+        assert!(matches!(
+            store.process_light_client_finality_update(&broken, 25, &fork_schedule),
+            Err(VerificationError::InvalidFinalityBranch { .. })
+        ));
+        assert_eq!(store.finalized_header.slot, 8);
+
+        store
+            .process_light_client_finality_update(&update, 25, &fork_schedule)
+            .unwrap();
+        assert_eq!(store.finalized_header, finalized_header);
+        assert_eq!(store.optimistic_header, update.attested_header.beacon);
+        assert!(store.best_valid_update.is_none());
+    }
+
+    #[test]
+    fn test_process_light_client_optimistic_update() {
+        let fork_schedule = altair_fork_schedule();
+        let committee = Committee::new(1);
+        let mut store = store_at(8, &committee);
+
+        let mut update = minimal::LightClientOptimisticUpdate::default();
+        update.attested_header.slot = 24;
+        update.signature_slot = 25;
+        update.sync_aggregate =
+            committee.sign::<SLOTS_PER_EPOCH>(&update.attested_header, 25, &fork_schedule);
+
+        // a signature of another committee is rejected
+        let mut forged = update.clone();
+        forged.sync_aggregate =
+            Committee::new(2).sign::<SLOTS_PER_EPOCH>(&update.attested_header, 25, &fork_schedule);
+        assert!(matches!(
+            store.process_light_client_optimistic_update(&forged, 25, &fork_schedule),
+            Err(VerificationError::SignatureMismatch { .. })
+        ));
+        assert_eq!(store.optimistic_header.slot, 8);
+
+        // only the optimistic header moves, the update is kept in case it has to be force-applied
+        store
+            .process_light_client_optimistic_update(&update, 25, &fork_schedule)
+            .unwrap();
+        assert_eq!(store.optimistic_header, update.attested_header);
+        assert_eq!(store.finalized_header.slot, 8);
+        assert!(store.best_valid_update.is_some());
+    }
+
+    /// A Capella header at `slot` whose block body commits to a payload with the slot as block number
+    fn capella_header(slot: Slot, state_root: Root) -> minimal::LightClientHeader {
+        let mut header = minimal::LightClientHeader::default();
+        header.execution.block_number = slot;
+        (header.beacon.body_root, header.execution_branch) = prove_execution(&header.execution);
+        header.beacon.slot = slot;
+        header.beacon.state_root = state_root;
+        header
+    }
+
+    #[test]
+    fn test_capella_store_keeps_execution_payloads() {
+        let fork_schedule = ForkSchedule {
+            capella_fork_epoch: 0,
+            deneb_fork_epoch: Epoch::MAX,
+            ..ForkSchedule::default()
+        };
+        let committee = Committee::new(1);
+        let mut store = minimal::LightClientStoreCapella::new(
+            capella_header(8, Root::default()),
+            committee.committee.clone(),
+        );
+
+        let finalized_header = capella_header(16, Root::default());
+        let finalized_root = finalized_header.beacon.clone().hash_tree_root().unwrap();
+        let (state_root, branches) =
+            merkle_tree(&[(minimal::FINALIZED_ROOT_GINDEX, finalized_root)]);
+        let mut update = minimal::LightClientFinalityUpdate::default();
+        update.attested_header = capella_header(24, state_root);
+        update.finalized_header = finalized_header.clone();
+        update.finality_branch = Vector::try_from(bytes32_branch(&branches[0])).unwrap();
+        update.signature_slot = 25;
+        update.sync_aggregate =
+            committee.sign::<SLOTS_PER_EPOCH>(&update.attested_header.beacon, 25, &fork_schedule);
+
+        let mut unproven = update.clone();
+        unproven.attested_header.execution.block_number = 0;
+        assert!(matches!(
+            store.process_light_client_finality_update(&unproven, 25, &fork_schedule),
+            Err(VerificationError::InvalidExecutionBranch { .. })
+        ));
+
+        store
+            .process_light_client_finality_update(&update, 25, &fork_schedule)
+            .unwrap();
+        assert_eq!(store.finalized_header, finalized_header);
+        assert_eq!(store.optimistic_header, update.attested_header);
+
+        let mut update = minimal::LightClientOptimisticUpdateCapella::default();
+        update.attested_header = capella_header(32, Root::default());
+        update.signature_slot = 33;
+        update.sync_aggregate =
+            committee.sign::<SLOTS_PER_EPOCH>(&update.attested_header.beacon, 33, &fork_schedule);
+        store
+            .process_light_client_optimistic_update(&update, 33, &fork_schedule)
+            .unwrap();
+        assert_eq!(store.optimistic_header.execution.block_number, 32);
+        assert_eq!(store.finalized_header.execution.block_number, 16);
+    }
+
+    #[test]
+    fn test_initialize_from_bootstrap() {
+        let fork_schedule = altair_fork_schedule();
+        let committee = Committee::new(1);
+        let (bootstrap, trusted_block_root) = bootstrap_at(8, &committee);
+        let initialize = |bootstrap: &minimal::LightClientBootstrap, trusted_block_root| {
+            minimal::LightClientStore::initialize(trusted_block_root, bootstrap, &fork_schedule)
+        };
+
+        let store = initialize(&bootstrap, trusted_block_root).unwrap();
+        assert_eq!(store.finalized_header, bootstrap.header.beacon);
+        assert_eq!(store.current_sync_committee, committee.committee);
+
+        let other_root = Node::try_from(&[1u8; 32][..]).unwrap();
+        assert!(matches!(
+            initialize(&bootstrap, other_root),
+            Err(VerificationError::UntrustedBlockRoot { block_root, trusted_block_root: trusted })
+                if block_root == trusted_block_root && trusted == other_root
+        ));
+
+        // a committee that is not in the state of the header
+        let mut wrong_committee = bootstrap.clone();
+        wrong_committee.current_sync_committee = Committee::new(2).committee;
+        assert!(matches!(
+            initialize(&wrong_committee, trusted_block_root),
+            Err(VerificationError::InvalidCurrentSyncCommitteeBranch { .. })
+        ));
+
+        let mut bad_branch = bootstrap.clone();
+        bad_branch.current_sync_committee_branch[0] = other_root;
+        assert!(matches!(
+            initialize(&bad_branch, trusted_block_root),
+            Err(VerificationError::InvalidCurrentSyncCommitteeBranch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_apply_light_client_update() {
+        let fork_schedule = altair_fork_schedule();
+        let [first, second, third] = [1, 2, 3].map(Committee::new);
+        let mut store = store_at(8, &first);
+
+        // the next sync committee is learnt from an update finalized in the store period
+        let update = signed_update(
+            &first,
+            24,
+            Some(&second),
+            Some(&header_at(16)),
+            &fork_schedule,
+        );
+        store.apply_light_client_update(&update).unwrap();
+        assert_eq!(store.finalized_header, header_at(16));
+        assert_eq!(store.optimistic_header, header_at(16));
+        assert_eq!(store.current_sync_committee, first.committee);
+        assert_eq!(store.next_sync_committee, second.committee);
+
+        // finalizing the next period rotates the committees
+        let update = signed_update(
+            &second,
+            72,
+            Some(&third),
+            Some(&header_at(66)),
+            &fork_schedule,
+        );
+        store.apply_light_client_update(&update).unwrap();
+        assert_eq!(store.finalized_header, header_at(66));
+        assert_eq!(store.current_sync_committee, second.committee);
+        assert_eq!(store.next_sync_committee, third.committee);
+
+        // without a next sync committee the store cannot leave its period
+        let mut store = store_at(8, &first);
+        assert!(matches!(
+            store.apply_light_client_update(&update),
+            Err(VerificationError::UnknownSyncCommittee {
+                period: 1,
+                store_period: 0
+            })
+        ));
+        assert_eq!(store.finalized_header, header_at(8));
+    }
+
+    #[test]
+    fn test_best_valid_update_is_kept_until_forced() {
+        let fork_schedule = altair_fork_schedule();
+        let [first, second] = [1, 2].map(Committee::new);
+        let mut store = store_at(8, &first);
+
+        // without finality a valid update is kept instead of applied
+        let with_committee = signed_update(&first, 24, Some(&second), None, &fork_schedule);
+        store
+            .process_light_client_update(&with_committee, 25, &fork_schedule)
+            .unwrap();
+        assert_eq!(store.best_valid_update, Some(with_committee.clone()));
+        assert_eq!(store.optimistic_header, with_committee.attested_header);
+        assert_eq!(store.finalized_header, header_at(8));
+        assert!(!store.is_next_sync_committee_known());
+
+        // a newer update is worse without the next sync committee
+        let without_committee = signed_update(&first, 32, None, None, &fork_schedule);
+        store
+            .process_light_client_update(&without_committee, 33, &fork_schedule)
+            .unwrap();
+        assert_eq!(store.best_valid_update, Some(with_committee.clone()));
+        assert_eq!(store.optimistic_header, without_committee.attested_header);
+
+        // the best valid update is only forced once finality has stalled for UPDATE_TIMEOUT slots
+        let timeout = 8 + minimal::LightClientStore::UPDATE_TIMEOUT;
+        store
+            .process_light_client_store_force_update(timeout)
+            .unwrap();
+        assert!(store.best_valid_update.is_some());
+        store
+            .process_light_client_store_force_update(timeout + 1)
+            .unwrap();
+        assert!(store.best_valid_update.is_none());
+        assert_eq!(store.finalized_header, with_committee.attested_header);
+        assert_eq!(store.next_sync_committee, second.committee);
+
+        // an update that cannot be applied is kept
+        let mut store = store_at(8, &first);
+        let next_period = signed_update(&first, 70, None, None, &fork_schedule);
+        store.best_valid_update = Some(next_period.clone());
+        assert!(matches!(
+            store.process_light_client_store_force_update(timeout + 1),
+            Err(VerificationError::UnknownSyncCommittee { .. })
+        ));
+        assert_eq!(store.best_valid_update, Some(next_period));
+        assert_eq!(store.finalized_header, header_at(8));
+    }
+
+    #[test]
+    fn test_sync_committee_period_transition() {
+        let fork_schedule = altair_fork_schedule();
+        let [first, second, third] = [1, 2, 3].map(Committee::new);
+        let mut store = store_at(8, &first);
+
+        // the committee of the next period is unknown until an update proves it
+        let next_period = signed_update(
+            &second,
+            72,
+            Some(&third),
+            Some(&header_at(66)),
+            &fork_schedule,
+        );
+        assert!(matches!(
+            store.process_light_client_update(&next_period, 73, &fork_schedule),
+            Err(VerificationError::UnknownSyncCommittee {
+                period: 1,
+                store_period: 0
+            })
+        ));
+
+        let update = signed_update(
+            &first,
+            24,
+            Some(&second),
+            Some(&header_at(16)),
+            &fork_schedule,
+        );
+        store
+            .process_light_client_update(&update, 25, &fork_schedule)
+            .unwrap();
+        assert_eq!(store.next_sync_committee, second.committee);
+
+        // the next period must be signed by the next sync committee
+        let signed_by_first = signed_update(
+            &first,
+            72,
+            Some(&third),
+            Some(&header_at(66)),
+            &fork_schedule,
+        );
+        assert!(matches!(
+            store.process_light_client_update(&signed_by_first, 73, &fork_schedule),
+            Err(VerificationError::SignatureMismatch { .. })
+        ));
+
+        store
+            .process_light_client_update(&next_period, 73, &fork_schedule)
+            .unwrap();
+        assert_eq!(store.finalized_header, header_at(66));
+        assert_eq!(store.optimistic_header, next_period.attested_header);
+        assert_eq!(store.current_sync_committee, second.committee);
+        assert_eq!(store.next_sync_committee, third.committee);
+        assert!(store.best_valid_update.is_none());
+    }
+
+    #[test]
+    fn test_verify_capella_update() {
+        let fork_schedule = altair_fork_schedule();
+        let [first, second] = [1, 2].map(Committee::new);
+        let update = capella_update(&signed_update(
+            &first,
+            24,
+            Some(&second),
+            Some(&header_at(16)),
+            &fork_schedule,
+        ));
+        assert!(update
+            .verify::<SLOTS_PER_EPOCH>(&fork_schedule, &first.committee)
+            .is_ok());
+        assert!(matches!(
+            update.verify::<SLOTS_PER_EPOCH>(&fork_schedule, &second.committee),
+            Err(VerificationError::SignatureMismatch {
+                attested_slot: 24,
+                ..
+            })
+        ));
+
+        // the slot rules apply to every fork
+        assert!(matches!(
+            update.validate_slots(23),
+            Err(VerificationError::FutureSignatureSlot {
+                signature_slot: 25,
+                current_slot: 23
+            })
+        ));
+        let mut unordered = update.clone();
+        unordered.signature_slot = 24;
+        assert!(matches!(
+            unordered.verify::<SLOTS_PER_EPOCH>(&fork_schedule, &first.committee),
+            Err(VerificationError::InvalidSlotOrder { .. })
+        ));
+    }
+
+    #[test]
+    fn test_invalid_finality_branch() {
+        let fork_schedule = altair_fork_schedule();
+        let [first, second] = [1, 2].map(Committee::new);
+        let mut update = capella_update(&signed_update(
+            &first,
+            24,
+            Some(&second),
+            Some(&header_at(16)),
+            &fork_schedule,
+        ));
+        update.finalized_header.beacon.slot = 17;
+        let finalized_root = update
+            .finalized_header
+            .beacon
+            .clone()
+            .hash_tree_root()
+            .unwrap();
+        let attested_state_root = update.attested_header.beacon.state_root;
+        assert!(matches!(
+            update.verify::<SLOTS_PER_EPOCH>(&fork_schedule, &first.committee),
+            Err(VerificationError::InvalidFinalityBranch {
+                finalized_block_root,
+                attested_state_root: state_root,
+            }) if finalized_block_root == finalized_root && state_root == attested_state_root
+        ));
+    }
+
+    #[test]
+    fn test_invalid_next_sync_committee_branch() {
+        let fork_schedule = altair_fork_schedule();
+        let [first, second, third] = [1, 2, 3].map(Committee::new);
+        let mut update = capella_update(&signed_update(
+            &first,
+            24,
+            Some(&second),
+            None,
+            &fork_schedule,
+        ));
+        update.next_sync_committee = third.committee;
+        assert!(matches!(
+            update.verify::<SLOTS_PER_EPOCH>(&fork_schedule, &first.committee),
+            Err(VerificationError::InvalidNextSyncCommitteeBranch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_next_sync_committee_mismatch() {
+        let fork_schedule = altair_fork_schedule();
+        let [first, second, third] = [1, 2, 3].map(Committee::new);
+        let mut store = store_at(8, &first);
+        let update = signed_update(
+            &first,
+            24,
+            Some(&second),
+            Some(&header_at(16)),
+            &fork_schedule,
+        );
+        store
+            .process_light_client_update(&update, 25, &fork_schedule)
+            .unwrap();
+
+        // a later update of the same period cannot change the known next sync committee
+        let conflicting = signed_update(
+            &first,
+            32,
+            Some(&third),
+            Some(&header_at(24)),
+            &fork_schedule,
+        );
+        assert!(matches!(
+            store.process_light_client_update(&conflicting, 33, &fork_schedule),
+            Err(VerificationError::NextSyncCommitteeMismatch)
+        ));
+        assert_eq!(store.next_sync_committee, second.committee);
+    }
+
+    #[test]
+    fn test_no_signers() {
+        let committee = Committee::new(1);
+        assert!(matches!(
+            update_with_participants(0).verify_signature::<SLOTS_PER_EPOCH>(
+                &ForkSchedule::default(),
+                &committee.committee
+            ),
+            Err(VerificationError::NoSigners)
+        ));
+    }
+
+    #[test]
+    fn test_unproven_next_sync_committee() {
+        let fork_schedule = altair_fork_schedule();
+        let [first, second] = [1, 2].map(Committee::new);
+        let store = store_at(8, &first);
+        let mut update = signed_update(&first, 24, Some(&second), None, &fork_schedule);
+        update.next_sync_committee_branch = Vector::default();
+        assert!(matches!(
+            store.validate_light_client_update(&update, 25, &fork_schedule),
+            Err(VerificationError::UnprovenNextSyncCommittee)
+        ));
+    }
+
+    #[test]
+    fn test_stale_update() {
+        let fork_schedule = altair_fork_schedule();
+        let committee = Committee::new(1);
+        let store = store_at(16, &committee);
+        let update = signed_update(&committee, 12, None, None, &fork_schedule);
+        assert!(matches!(
+            store.validate_light_client_update(&update, 13, &fork_schedule),
+            Err(VerificationError::StaleUpdate {
+                attested_slot: 12,
+                finalized_slot: 16
+            })
+        ));
+    }
+}
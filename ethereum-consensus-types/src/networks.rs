@@ -0,0 +1,308 @@
+//! Configuration of well-known public networks, so their `ForkData` never has to be written by hand.
+use crate::config::{ChainConfig, FAR_FUTURE_EPOCH};
+use crate::presets::PresetBase;
+use crate::{ForkSchedule, Root};
+use hex_literal::hex;
+use ssz_rs::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Network {
+    Mainnet,
+    Sepolia,
+    Holesky,
+    Hoodi,
+    Gnosis,
+    Chiado,
+}
+
+impl Network {
+    pub const ALL: [Network; 6] = [
+        Self::Mainnet,
+        Self::Sepolia,
+        Self::Holesky,
+        Self::Hoodi,
+        Self::Gnosis,
+        Self::Chiado,
+    ];
+
+    /// Name of the network, as the `CONFIG_NAME` of its config
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Mainnet => "mainnet",
+            Self::Sepolia => "sepolia",
+            Self::Holesky => "holesky",
+            Self::Hoodi => "hoodi",
+            Self::Gnosis => "gnosis",
+            Self::Chiado => "chiado",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|network| network.name() == name)
+    }
+
+    pub fn preset(&self) -> PresetBase {
+        match self {
+            Self::Mainnet | Self::Sepolia | Self::Holesky | Self::Hoodi => PresetBase::Mainnet,
+            Self::Gnosis | Self::Chiado => PresetBase::Gnosis,
+        }
+    }
+
+    pub fn genesis_validators_root(&self) -> Root {
+        let root = match self {
+            Self::Mainnet => {
+                hex!("4b363db94e286120d76eb905340fdd4e54bfe9f06bf33ff6cf5ad27f511bfe95")
+            }
+            Self::Sepolia => {
+                hex!("d8ea171f3c94aea21ebc42a1ed61052acf3f9209c00e4efbaaddac09ed9b8078")
+            }
+            Self::Holesky => {
+                hex!("9143aa7c615a7f7115e2b6aac319c03529df8242ae705fba9df39b79c59fa8b1")
+            }
+            Self::Hoodi => {
+                hex!("212f13fc4df078b6cb7db228f1c8307566dcecf900867401a92023d7ba99cb5f")
+            }
+            Self::Gnosis => {
+                hex!("f5dcb5564e829aab27264b9becd5dfaa017085611224cb3036f573368dbb9d47")
+            }
+            Self::Chiado => {
+                hex!("9d642dac73058fbf39c0ae41ab1e34e4d889043cb199851ded7095bc99eb4c1e")
+            }
+        };
+        // can unwrap as the root is 32 bytes
+        Node::try_from(&root[..]).unwrap()
+    }
+
+    /// Unix time of the genesis slot, as recorded in the genesis state
+    pub fn genesis_time(&self) -> u64 {
+        match self {
+            Self::Mainnet => 1606824023,
+            Self::Sepolia => 1655733600,
+            Self::Holesky => 1695902400,
+            Self::Hoodi => 1742213400,
+            Self::Gnosis => 1638993340,
+            Self::Chiado => 1665396300,
+        }
+    }
+
+    pub fn fork_schedule(&self) -> ForkSchedule {
+        self.chain_config()
+            .fork_schedule(self.genesis_validators_root())
+    }
+
+    pub fn chain_config(&self) -> ChainConfig {
+        match self {
+            Self::Mainnet => mainnet_config(),
+            Self::Sepolia => ChainConfig {
+                config_name: "sepolia".into(),
+                min_genesis_active_validator_count: 1300,
+                min_genesis_time: 1655647200,
+                genesis_fork_version: hex!("90000069"),
+                genesis_delay: 86400,
+                altair_fork_version: hex!("90000070"),
+                altair_fork_epoch: 50,
+                bellatrix_fork_version: hex!("90000071"),
+                bellatrix_fork_epoch: 100,
+                capella_fork_version: hex!("90000072"),
+                capella_fork_epoch: 56832,
+                deneb_fork_version: hex!("90000073"),
+                deneb_fork_epoch: 132608,
+                electra_fork_version: hex!("90000074"),
+                electra_fork_epoch: 222464,
+                fulu_fork_version: hex!("90000075"),
+                fulu_fork_epoch: 272640,
+                ..mainnet_config()
+            },
+            Self::Holesky => ChainConfig {
+                config_name: "holesky".into(),
+                min_genesis_time: 1695902100,
+                genesis_fork_version: hex!("01017000"),
+                genesis_delay: 300,
+                altair_fork_version: hex!("02017000"),
+                altair_fork_epoch: 0,
+                bellatrix_fork_version: hex!("03017000"),
+                bellatrix_fork_epoch: 0,
+                capella_fork_version: hex!("04017000"),
+                capella_fork_epoch: 256,
+                deneb_fork_version: hex!("05017000"),
+                deneb_fork_epoch: 29696,
+                electra_fork_version: hex!("06017000"),
+                electra_fork_epoch: 115968,
+                fulu_fork_version: hex!("07017000"),
+                fulu_fork_epoch: 165120,
+                ..mainnet_config()
+            },
+            Self::Hoodi => ChainConfig {
+                config_name: "hoodi".into(),
+                min_genesis_time: 1742212800,
+                genesis_fork_version: hex!("10000910"),
+                genesis_delay: 600,
+                altair_fork_version: hex!("20000910"),
+                altair_fork_epoch: 0,
+                bellatrix_fork_version: hex!("30000910"),
+                bellatrix_fork_epoch: 0,
+                capella_fork_version: hex!("40000910"),
+                capella_fork_epoch: 0,
+                deneb_fork_version: hex!("50000910"),
+                deneb_fork_epoch: 0,
+                electra_fork_version: hex!("60000910"),
+                electra_fork_epoch: 2048,
+                fulu_fork_version: hex!("70000910"),
+                fulu_fork_epoch: 50688,
+                ..mainnet_config()
+            },
+            Self::Gnosis => gnosis_config(),
+            Self::Chiado => ChainConfig {
+                config_name: "chiado".into(),
+                min_genesis_active_validator_count: 6000,
+                min_genesis_time: 1665396000,
+                genesis_fork_version: hex!("0000006f"),
+                genesis_delay: 300,
+                altair_fork_version: hex!("0100006f"),
+                altair_fork_epoch: 90,
+                bellatrix_fork_version: hex!("0200006f"),
+                bellatrix_fork_epoch: 180,
+                capella_fork_version: hex!("0300006f"),
+                capella_fork_epoch: 244224,
+                deneb_fork_version: hex!("0400006f"),
+                deneb_fork_epoch: 516608,
+                electra_fork_version: hex!("0500006f"),
+                electra_fork_epoch: 948224,
+                fulu_fork_version: hex!("0600006f"),
+                min_per_epoch_churn_limit: 2,
+                ..gnosis_config()
+            },
+        }
+    }
+}
+
+fn mainnet_config() -> ChainConfig {
+    ChainConfig {
+        preset_base: PresetBase::Mainnet.name().into(),
+        config_name: "mainnet".into(),
+        min_genesis_active_validator_count: 16384,
+        min_genesis_time: 1606824000,
+        genesis_fork_version: hex!("00000000"),
+        genesis_delay: 604800,
+        altair_fork_version: hex!("01000000"),
+        altair_fork_epoch: 74240,
+        bellatrix_fork_version: hex!("02000000"),
+        bellatrix_fork_epoch: 144896,
+        capella_fork_version: hex!("03000000"),
+        capella_fork_epoch: 194048,
+        deneb_fork_version: hex!("04000000"),
+        deneb_fork_epoch: 269568,
+        electra_fork_version: hex!("05000000"),
+        electra_fork_epoch: 364032,
+        fulu_fork_version: hex!("06000000"),
+        fulu_fork_epoch: 411392,
+        seconds_per_slot: 12,
+        seconds_per_eth1_block: 14,
+        min_validator_withdrawability_delay: 256,
+        shard_committee_period: 256,
+        eth1_follow_distance: 2048,
+        min_per_epoch_churn_limit: 4,
+        churn_limit_quotient: 65536,
+    }
+}
+
+fn gnosis_config() -> ChainConfig {
+    ChainConfig {
+        preset_base: PresetBase::Gnosis.name().into(),
+        config_name: "gnosis".into(),
+        min_genesis_active_validator_count: 4096,
+        min_genesis_time: 1638968400,
+        genesis_fork_version: hex!("00000064"),
+        genesis_delay: 6000,
+        altair_fork_version: hex!("01000064"),
+        altair_fork_epoch: 512,
+        bellatrix_fork_version: hex!("02000064"),
+        bellatrix_fork_epoch: 385536,
+        capella_fork_version: hex!("03000064"),
+        capella_fork_epoch: 648704,
+        deneb_fork_version: hex!("04000064"),
+        deneb_fork_epoch: 889856,
+        electra_fork_version: hex!("05000064"),
+        electra_fork_epoch: 1337856,
+        fulu_fork_version: hex!("06000064"),
+        fulu_fork_epoch: FAR_FUTURE_EPOCH,
+        seconds_per_slot: 5,
+        seconds_per_eth1_block: 6,
+        min_validator_withdrawability_delay: 256,
+        shard_committee_period: 256,
+        eth1_follow_distance: 1024,
+        min_per_epoch_churn_limit: 4,
+        churn_limit_quotient: 4096,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ForkData;
+
+    #[test]
+    fn test_network_registry() {
+        for network in Network::ALL {
+            assert_eq!(Network::from_name(network.name()), Some(network));
+            let config = network.chain_config();
+            assert_eq!(config.config_name, network.name());
+            assert_eq!(
+                PresetBase::from_name(&config.preset_base),
+                Some(network.preset())
+            );
+            assert!(config.min_genesis_time <= network.genesis_time());
+        }
+
+        // mainnet fork digests
+        let fork_digest = |fork_version| {
+            ForkData {
+                fork_version,
+                genesis_validators_root: Network::Mainnet.genesis_validators_root(),
+            }
+            .fork_digest()
+        };
+        let schedule = Network::Mainnet.fork_schedule();
+        assert_eq!(
+            fork_digest(schedule.fork_version_at_epoch(0)),
+            hex!("b5303f2a")
+        );
+        assert_eq!(
+            fork_digest(schedule.fork_version_at_epoch(74240)),
+            hex!("afcaaba0")
+        );
+        assert_eq!(
+            fork_digest(schedule.fork_version_at_epoch(194048)),
+            hex!("bba4da96")
+        );
+
+        // (network, genesis fork digest, deneb fork digest)
+        let known_digests = [
+            (Network::Sepolia, hex!("a8fee8ee"), hex!("d31f6191")),
+            (Network::Holesky, hex!("219d34fe"), hex!("69ae0e99")),
+            (Network::Hoodi, hex!("5df5c106"), hex!("d2f1997f")),
+            (Network::Gnosis, hex!("bc9a6864"), hex!("3ebfd484")),
+            (Network::Chiado, hex!("48ea167c"), hex!("9d33b675")),
+        ];
+        for (network, genesis_digest, deneb_digest) in known_digests {
+            let config = network.chain_config();
+            let fork_digest = |fork_version| {
+                ForkData {
+                    fork_version,
+                    genesis_validators_root: network.genesis_validators_root(),
+                }
+                .fork_digest()
+            };
+            assert_eq!(
+                fork_digest(config.genesis_fork_version),
+                genesis_digest,
+                "{network:?}"
+            );
+            assert_eq!(
+                fork_digest(config.deneb_fork_version),
+                deneb_digest,
+                "{network:?}"
+            );
+        }
+    }
+}
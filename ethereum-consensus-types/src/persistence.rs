@@ -0,0 +1,451 @@
+//! Storage of the trusted light client state, so embedders can plug in their own database.
+use crate::{LightClientStoreSnapshot, LightClientUpdate, SyncCommittee};
+use alloc::vec::Vec;
+
+/// A backend persisting the trusted state of a light client.
+///
+/// The store itself is persisted as a `LightClientStoreSnapshot` so it is versioned independently of
+/// the backend. Accepted updates and proven sync committees are kept by sync committee period.
+pub trait LightClientPersistence<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const NEXT_SYNC_COMMITTEE_GINDEX: usize,
+    const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize,
+    const FINALIZED_ROOT_GINDEX: usize,
+    const FINALIZED_ROOT_PROOF_SIZE: usize,
+>
+{
+    type Error;
+
+    /// Load the last saved store, `None` if nothing was saved yet
+    fn load_store(
+        &self,
+    ) -> Result<
+        Option<
+            LightClientStoreSnapshot<
+                SYNC_COMMITTEE_SIZE,
+                NEXT_SYNC_COMMITTEE_GINDEX,
+                NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+                FINALIZED_ROOT_GINDEX,
+                FINALIZED_ROOT_PROOF_SIZE,
+            >,
+        >,
+        Self::Error,
+    >;
+
+    /// Replace the saved store
+    fn save_store(
+        &mut self,
+        snapshot: &LightClientStoreSnapshot<
+            SYNC_COMMITTEE_SIZE,
+            NEXT_SYNC_COMMITTEE_GINDEX,
+            NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+            FINALIZED_ROOT_GINDEX,
+            FINALIZED_ROOT_PROOF_SIZE,
+        >,
+    ) -> Result<(), Self::Error>;
+
+    /// Record an accepted update under the sync committee `period` it was signed in
+    fn append_update(
+        &mut self,
+        period: u64,
+        update: &LightClientUpdate<
+            SYNC_COMMITTEE_SIZE,
+            NEXT_SYNC_COMMITTEE_GINDEX,
+            NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+            FINALIZED_ROOT_GINDEX,
+            FINALIZED_ROOT_PROOF_SIZE,
+        >,
+    ) -> Result<(), Self::Error>;
+
+    /// The updates accepted for `period`, in the order they were appended
+    fn load_updates(
+        &self,
+        period: u64,
+    ) -> Result<
+        Vec<
+            LightClientUpdate<
+                SYNC_COMMITTEE_SIZE,
+                NEXT_SYNC_COMMITTEE_GINDEX,
+                NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+                FINALIZED_ROOT_GINDEX,
+                FINALIZED_ROOT_PROOF_SIZE,
+            >,
+        >,
+        Self::Error,
+    >;
+
+    /// Record the sync committee responsible for `period`
+    fn save_sync_committee(
+        &mut self,
+        period: u64,
+        committee: &SyncCommittee<SYNC_COMMITTEE_SIZE>,
+    ) -> Result<(), Self::Error>;
+
+    /// The sync committee responsible for `period`, `None` if it was never saved
+    fn load_sync_committee(
+        &self,
+        period: u64,
+    ) -> Result<Option<SyncCommittee<SYNC_COMMITTEE_SIZE>>, Self::Error>;
+}
+
+#[cfg(feature = "persistence")]
+pub use file::{FileStore, FileStoreError};
+
+#[cfg(feature = "persistence")]
+mod file {
+    use super::LightClientPersistence;
+    use crate::snapshot::SnapshotError;
+    use crate::{LightClientStoreSnapshot, LightClientUpdate, SyncCommittee};
+    use std::collections::BTreeMap;
+    use std::fs;
+    use std::io::{self, Write};
+    use std::path::{Path, PathBuf};
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    const STORE_FILE: &str = "store.ssz_snappy";
+    const UPDATES_DIR: &str = "updates";
+    const COMMITTEES_DIR: &str = "sync_committees";
+
+    #[derive(Debug)]
+    pub enum FileStoreError {
+        Io(io::Error),
+        Snappy(snap::Error),
+        Snapshot(SnapshotError),
+    }
+
+    impl From<io::Error> for FileStoreError {
+        fn from(value: io::Error) -> Self {
+            Self::Io(value)
+        }
+    }
+
+    impl From<snap::Error> for FileStoreError {
+        fn from(value: snap::Error) -> Self {
+            Self::Snappy(value)
+        }
+    }
+
+    impl From<SnapshotError> for FileStoreError {
+        fn from(value: SnapshotError) -> Self {
+            Self::Snapshot(value)
+        }
+    }
+
+    impl From<ssz_rs::SerializeError> for FileStoreError {
+        fn from(value: ssz_rs::SerializeError) -> Self {
+            Self::Snapshot(value.into())
+        }
+    }
+
+    impl From<ssz_rs::DeserializeError> for FileStoreError {
+        fn from(value: ssz_rs::DeserializeError) -> Self {
+            Self::Snapshot(value.into())
+        }
+    }
+
+    /// Persists the light client state as ssz_snappy files in a local directory:
+    ///
+    /// ```text
+    /// store.ssz_snappy
+    /// updates/<period>/<index>.ssz_snappy
+    /// sync_committees/<period>.ssz_snappy
+    /// ```
+    ///
+    /// Every file is written to a temporary file first and then renamed over its destination,
+    /// so a crash never leaves a partially written file behind.
+    #[derive(Clone, Debug)]
+    pub struct FileStore {
+        dir: PathBuf,
+        // the next free update index by period, counted from the directory on first use
+        next_update_index: BTreeMap<u64, u64>,
+    }
+
+    impl FileStore {
+        /// Open the store in `dir`, creating the directory if it does not exist
+        pub fn open(dir: impl Into<PathBuf>) -> Result<Self, FileStoreError> {
+            let dir = dir.into();
+            fs::create_dir_all(&dir)?;
+            Ok(Self {
+                dir,
+                next_update_index: BTreeMap::new(),
+            })
+        }
+
+        pub fn dir(&self) -> &Path {
+            &self.dir
+        }
+
+        fn updates_dir(&self, period: u64) -> PathBuf {
+            self.dir.join(UPDATES_DIR).join(period.to_string())
+        }
+
+        fn committee_path(&self, period: u64) -> PathBuf {
+            self.dir
+                .join(COMMITTEES_DIR)
+                .join(format!("{period}.ssz_snappy"))
+        }
+    }
+
+    /// Write `bytes` snappy compressed to `path` by renaming a synced temporary file over it
+    fn write_atomic(path: &Path, bytes: &[u8]) -> Result<(), FileStoreError> {
+        let dir = path.parent().unwrap_or(Path::new("."));
+        fs::create_dir_all(dir)?;
+        let compressed = snap::raw::Encoder::new().compress_vec(bytes)?;
+        let tmp_path = tmp_path(dir);
+        let mut file = fs::File::create(&tmp_path)?;
+        file.write_all(&compressed)?;
+        file.sync_all()?;
+        fs::rename(&tmp_path, path)?;
+        // persist the rename itself
+        sync_dir(dir)
+    }
+
+    /// Write `bytes` snappy compressed to the first free `<index>.ssz_snappy` in `dir` starting at
+    /// `index`, returning the index it was written to.
+    ///
+    /// The synced temporary file is hard linked to its destination, which unlike a rename fails if
+    /// another writer took the index first, so no update is ever replaced.
+    fn write_new(dir: &Path, mut index: u64, bytes: &[u8]) -> Result<u64, FileStoreError> {
+        fs::create_dir_all(dir)?;
+        let compressed = snap::raw::Encoder::new().compress_vec(bytes)?;
+        let tmp_path = tmp_path(dir);
+        let mut file = fs::File::create(&tmp_path)?;
+        file.write_all(&compressed)?;
+        file.sync_all()?;
+        let linked = loop {
+            match fs::hard_link(&tmp_path, dir.join(format!("{index}.ssz_snappy"))) {
+                Ok(()) => break Ok(index),
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => index += 1,
+                Err(err) => break Err(err),
+            }
+        };
+        fs::remove_file(&tmp_path)?;
+        let index = linked?;
+        // persist the link itself
+        sync_dir(dir)?;
+        Ok(index)
+    }
+
+    /// A temporary file in `dir` that is unique to this process and call, so concurrent writers
+    /// never write to the same one
+    fn tmp_path(dir: &Path) -> PathBuf {
+        static TMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+        dir.join(format!(
+            "{}-{}.tmp",
+            std::process::id(),
+            TMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ))
+    }
+
+    /// Persist the entries of `dir` after one was renamed or linked into it
+    #[cfg(unix)]
+    fn sync_dir(dir: &Path) -> Result<(), FileStoreError> {
+        fs::File::open(dir)?.sync_all()?;
+        Ok(())
+    }
+
+    /// Directories cannot be opened as files on other platforms, which persist the entries together
+    /// with the file itself
+    #[cfg(not(unix))]
+    fn sync_dir(_dir: &Path) -> Result<(), FileStoreError> {
+        Ok(())
+    }
+
+    /// Number of updates in `dir`, which are numbered without gaps from zero
+    fn count_updates(dir: &Path) -> Result<u64, FileStoreError> {
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(err) => return Err(err.into()),
+        };
+        let mut count = 0;
+        for entry in entries {
+            if entry?
+                .path()
+                .extension()
+                .is_some_and(|ext| ext == "ssz_snappy")
+            {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Read and decompress `path`, `None` if it does not exist
+    fn read(path: &Path) -> Result<Option<Vec<u8>>, FileStoreError> {
+        match fs::read(path) {
+            Ok(compressed) => Ok(Some(snap::raw::Decoder::new().decompress_vec(&compressed)?)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    impl<
+            const SYNC_COMMITTEE_SIZE: usize,
+            const NEXT_SYNC_COMMITTEE_GINDEX: usize,
+            const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize,
+            const FINALIZED_ROOT_GINDEX: usize,
+            const FINALIZED_ROOT_PROOF_SIZE: usize,
+        >
+        LightClientPersistence<
+            SYNC_COMMITTEE_SIZE,
+            NEXT_SYNC_COMMITTEE_GINDEX,
+            NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+            FINALIZED_ROOT_GINDEX,
+            FINALIZED_ROOT_PROOF_SIZE,
+        > for FileStore
+    {
+        type Error = FileStoreError;
+
+        fn load_store(
+            &self,
+        ) -> Result<
+            Option<
+                LightClientStoreSnapshot<
+                    SYNC_COMMITTEE_SIZE,
+                    NEXT_SYNC_COMMITTEE_GINDEX,
+                    NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+                    FINALIZED_ROOT_GINDEX,
+                    FINALIZED_ROOT_PROOF_SIZE,
+                >,
+            >,
+            Self::Error,
+        > {
+            read(&self.dir.join(STORE_FILE))?
+                .map(|bytes| Ok(LightClientStoreSnapshot::decode(&bytes)?))
+                .transpose()
+        }
+
+        fn save_store(
+            &mut self,
+            snapshot: &LightClientStoreSnapshot<
+                SYNC_COMMITTEE_SIZE,
+                NEXT_SYNC_COMMITTEE_GINDEX,
+                NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+                FINALIZED_ROOT_GINDEX,
+                FINALIZED_ROOT_PROOF_SIZE,
+            >,
+        ) -> Result<(), Self::Error> {
+            write_atomic(&self.dir.join(STORE_FILE), &snapshot.encode()?)
+        }
+
+        fn append_update(
+            &mut self,
+            period: u64,
+            update: &LightClientUpdate<
+                SYNC_COMMITTEE_SIZE,
+                NEXT_SYNC_COMMITTEE_GINDEX,
+                NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+                FINALIZED_ROOT_GINDEX,
+                FINALIZED_ROOT_PROOF_SIZE,
+            >,
+        ) -> Result<(), Self::Error> {
+            let dir = self.updates_dir(period);
+            let next_index = match self.next_update_index.get(&period) {
+                Some(index) => *index,
+                None => count_updates(&dir)?,
+            };
+            let index = write_new(&dir, next_index, &ssz_rs::serialize(update)?)?;
+            self.next_update_index.insert(period, index + 1);
+            Ok(())
+        }
+
+        fn load_updates(
+            &self,
+            period: u64,
+        ) -> Result<
+            Vec<
+                LightClientUpdate<
+                    SYNC_COMMITTEE_SIZE,
+                    NEXT_SYNC_COMMITTEE_GINDEX,
+                    NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+                    FINALIZED_ROOT_GINDEX,
+                    FINALIZED_ROOT_PROOF_SIZE,
+                >,
+            >,
+            Self::Error,
+        > {
+            let dir = self.updates_dir(period);
+            let mut updates = Vec::new();
+            for index in 0.. {
+                match read(&dir.join(format!("{index}.ssz_snappy")))? {
+                    Some(bytes) => updates.push(ssz_rs::deserialize(&bytes)?),
+                    None => break,
+                }
+            }
+            Ok(updates)
+        }
+
+        fn save_sync_committee(
+            &mut self,
+            period: u64,
+            committee: &SyncCommittee<SYNC_COMMITTEE_SIZE>,
+        ) -> Result<(), Self::Error> {
+            write_atomic(&self.committee_path(period), &ssz_rs::serialize(committee)?)
+        }
+
+        fn load_sync_committee(
+            &self,
+            period: u64,
+        ) -> Result<Option<SyncCommittee<SYNC_COMMITTEE_SIZE>>, Self::Error> {
+            read(&self.committee_path(period))?
+                .map(|bytes| Ok(ssz_rs::deserialize(&bytes)?))
+                .transpose()
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+        use crate::presets::minimal;
+
+        type Persistence = dyn LightClientPersistence<
+            { minimal::SYNC_COMMITTEE_SIZE },
+            { minimal::NEXT_SYNC_COMMITTEE_GINDEX },
+            { minimal::NEXT_SYNC_COMMITTEE_PROOF_SIZE },
+            { minimal::FINALIZED_ROOT_GINDEX },
+            { minimal::FINALIZED_ROOT_PROOF_SIZE },
+            Error = FileStoreError,
+        >;
+
+        #[test]
+        fn test_file_store() {
+            let dir =
+                std::env::temp_dir().join(format!("light-client-store-{}", std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            let mut file_store = FileStore::open(&dir).unwrap();
+            let persistence: &mut Persistence = &mut file_store;
+
+            assert!(persistence.load_store().unwrap().is_none());
+            let mut store = minimal::LightClientStore::default();
+            store.finalized_header.slot = 64;
+            persistence
+                .save_store(&store.to_snapshot([1, 0, 0, 0]))
+                .unwrap();
+            let snapshot = persistence.load_store().unwrap().unwrap();
+            assert_eq!(minimal::LightClientStore::from_snapshot(snapshot), store);
+
+            let mut update = minimal::LightClientUpdate::default();
+            persistence.append_update(1, &update).unwrap();
+            update.signature_slot = 65;
+            persistence.append_update(1, &update).unwrap();
+            let updates = persistence.load_updates(1).unwrap();
+            assert_eq!(updates.len(), 2);
+            assert_eq!(updates[1], update);
+            assert!(persistence.load_updates(2).unwrap().is_empty());
+            // a second handle on the same directory appends after the existing updates
+            let mut other_store = FileStore::open(&dir).unwrap();
+            let other: &mut Persistence = &mut other_store;
+            other.append_update(1, &update).unwrap();
+            persistence.append_update(1, &update).unwrap();
+            assert_eq!(persistence.load_updates(1).unwrap().len(), 4);
+
+            let committee = minimal::SyncCommittee::default();
+            persistence.save_sync_committee(1, &committee).unwrap();
+            assert_eq!(persistence.load_sync_committee(1).unwrap(), Some(committee));
+            assert_eq!(persistence.load_sync_committee(2).unwrap(), None);
+
+            fs::remove_dir_all(&dir).unwrap();
+        }
+    }
+}
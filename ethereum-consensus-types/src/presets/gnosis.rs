@@ -0,0 +1,17 @@
+crate::declare_preset! {
+    /// The gnosis preset as a `Spec`
+    Gnosis = "gnosis" {
+        SYNC_COMMITTEE_SIZE: 512,
+        CURRENT_SYNC_COMMITTEE_GINDEX: 54,
+        CURRENT_SYNC_COMMITTEE_PROOF_SIZE: 5,
+        NEXT_SYNC_COMMITTEE_GINDEX: 55,
+        NEXT_SYNC_COMMITTEE_PROOF_SIZE: 5,
+        FINALIZED_ROOT_GINDEX: 105,
+        FINALIZED_ROOT_PROOF_SIZE: 6,
+        BYTES_PER_LOGS_BLOOM: 256,
+        MAX_EXTRA_DATA_BYTES: 32,
+        SLOTS_PER_EPOCH: 16,
+        EPOCHS_PER_SYNC_COMMITTEE_PERIOD: 512,
+        MAX_DEPOSITS: 16,
+    }
+}
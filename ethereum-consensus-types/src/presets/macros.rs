@@ -0,0 +1,310 @@
+/// Declare a preset from its constants: the constants themselves, an alias of every container
+/// instantiated with them, and a `Spec` implementing marker type.
+///
+/// The constants are given by name in a fixed order and every gindex is checked at compile time
+/// to be as deep as its proof is long.
+///
+/// ```
+/// mod devnet {
+///     ethereum_consensus_types::declare_preset! {
+///         /// A devnet with a small sync committee
+///         Devnet = "devnet" {
+///             SYNC_COMMITTEE_SIZE: 16,
+///             CURRENT_SYNC_COMMITTEE_GINDEX: 54,
+///             CURRENT_SYNC_COMMITTEE_PROOF_SIZE: 5,
+///             NEXT_SYNC_COMMITTEE_GINDEX: 55,
+///             NEXT_SYNC_COMMITTEE_PROOF_SIZE: 5,
+///             FINALIZED_ROOT_GINDEX: 105,
+///             FINALIZED_ROOT_PROOF_SIZE: 6,
+///             BYTES_PER_LOGS_BLOOM: 256,
+///             MAX_EXTRA_DATA_BYTES: 32,
+///             SLOTS_PER_EPOCH: 8,
+///             EPOCHS_PER_SYNC_COMMITTEE_PERIOD: 8,
+///             MAX_DEPOSITS: 16,
+///         }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! declare_preset {
+    (
+        $(#[$meta:meta])*
+        $preset:ident = $name:literal {
+            SYNC_COMMITTEE_SIZE: $sync_committee_size:expr,
+            CURRENT_SYNC_COMMITTEE_GINDEX: $current_sync_committee_gindex:expr,
+            CURRENT_SYNC_COMMITTEE_PROOF_SIZE: $current_sync_committee_proof_size:expr,
+            NEXT_SYNC_COMMITTEE_GINDEX: $next_sync_committee_gindex:expr,
+            NEXT_SYNC_COMMITTEE_PROOF_SIZE: $next_sync_committee_proof_size:expr,
+            FINALIZED_ROOT_GINDEX: $finalized_root_gindex:expr,
+            FINALIZED_ROOT_PROOF_SIZE: $finalized_root_proof_size:expr,
+            BYTES_PER_LOGS_BLOOM: $bytes_per_logs_bloom:expr,
+            MAX_EXTRA_DATA_BYTES: $max_extra_data_bytes:expr,
+            SLOTS_PER_EPOCH: $slots_per_epoch:expr,
+            EPOCHS_PER_SYNC_COMMITTEE_PERIOD: $epochs_per_sync_committee_period:expr,
+            MAX_DEPOSITS: $max_deposits:expr,
+        }
+    ) => {
+        /// Name of the preset, as the `PRESET_BASE` of a config using it
+        pub const PRESET_NAME: &str = $name;
+
+        pub const SYNC_COMMITTEE_SIZE: usize = $sync_committee_size;
+        pub const CURRENT_SYNC_COMMITTEE_GINDEX: usize = $current_sync_committee_gindex;
+        pub const CURRENT_SYNC_COMMITTEE_PROOF_SIZE: usize = $current_sync_committee_proof_size;
+        pub const NEXT_SYNC_COMMITTEE_GINDEX: usize = $next_sync_committee_gindex;
+        pub const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize = $next_sync_committee_proof_size;
+        pub const FINALIZED_ROOT_GINDEX: usize = $finalized_root_gindex;
+        pub const FINALIZED_ROOT_PROOF_SIZE: usize = $finalized_root_proof_size;
+        pub const BYTES_PER_LOGS_BLOOM: usize = $bytes_per_logs_bloom;
+        pub const MAX_EXTRA_DATA_BYTES: usize = $max_extra_data_bytes;
+        pub const SLOTS_PER_EPOCH: usize = $slots_per_epoch;
+        pub const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize = $epochs_per_sync_committee_period;
+        pub const MAX_DEPOSITS: usize = $max_deposits;
+
+        const _: () = assert!(
+            $crate::presets::gindex_depth(CURRENT_SYNC_COMMITTEE_GINDEX)
+                == CURRENT_SYNC_COMMITTEE_PROOF_SIZE,
+            "CURRENT_SYNC_COMMITTEE_PROOF_SIZE must be the depth of CURRENT_SYNC_COMMITTEE_GINDEX"
+        );
+        const _: () = assert!(
+            $crate::presets::gindex_depth(NEXT_SYNC_COMMITTEE_GINDEX)
+                == NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+            "NEXT_SYNC_COMMITTEE_PROOF_SIZE must be the depth of NEXT_SYNC_COMMITTEE_GINDEX"
+        );
+        const _: () = assert!(
+            $crate::presets::gindex_depth(FINALIZED_ROOT_GINDEX) == FINALIZED_ROOT_PROOF_SIZE,
+            "FINALIZED_ROOT_PROOF_SIZE must be the depth of FINALIZED_ROOT_GINDEX"
+        );
+
+        pub type SyncAggregate = $crate::SyncAggregate<SYNC_COMMITTEE_SIZE>;
+
+        pub type SyncCommittee = $crate::SyncCommittee<SYNC_COMMITTEE_SIZE>;
+
+        pub type ExecutionPayloadHeader = $crate::light_client::ExecutionPayloadHeader<
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >;
+
+        pub type LightClientHeader =
+            $crate::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>;
+
+        pub type LightClientUpdate = $crate::LightClientUpdate<
+            SYNC_COMMITTEE_SIZE,
+            NEXT_SYNC_COMMITTEE_GINDEX,
+            NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+            FINALIZED_ROOT_GINDEX,
+            FINALIZED_ROOT_PROOF_SIZE,
+        >;
+        pub type LightClientUpdateCapella = $crate::LightClientUpdateCapella<
+            SYNC_COMMITTEE_SIZE,
+            NEXT_SYNC_COMMITTEE_GINDEX,
+            NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+            FINALIZED_ROOT_GINDEX,
+            FINALIZED_ROOT_PROOF_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >;
+
+        pub type LightClientBootstrap = $crate::LightClientBootstrap<
+            SYNC_COMMITTEE_SIZE,
+            CURRENT_SYNC_COMMITTEE_GINDEX,
+            CURRENT_SYNC_COMMITTEE_PROOF_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >;
+
+        pub type LightClientFinalityUpdate = $crate::LightClientFinalityUpdate<
+            SYNC_COMMITTEE_SIZE,
+            FINALIZED_ROOT_GINDEX,
+            FINALIZED_ROOT_PROOF_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >;
+
+        pub type LightClientOptimisticUpdate =
+            $crate::LightClientOptimisticUpdate<SYNC_COMMITTEE_SIZE>;
+
+        pub type LightClientOptimisticUpdateCapella = $crate::LightClientOptimisticUpdateCapella<
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >;
+
+        pub type ExecutionPayloadHeaderDeneb = $crate::light_client::ExecutionPayloadHeaderDeneb<
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >;
+
+        pub type LightClientHeaderDeneb =
+            $crate::LightClientHeaderDeneb<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>;
+
+        pub type LightClientBootstrapDeneb = $crate::LightClientBootstrapDeneb<
+            SYNC_COMMITTEE_SIZE,
+            CURRENT_SYNC_COMMITTEE_GINDEX,
+            CURRENT_SYNC_COMMITTEE_PROOF_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >;
+
+        pub type LightClientUpdateDeneb = $crate::LightClientUpdateDeneb<
+            SYNC_COMMITTEE_SIZE,
+            NEXT_SYNC_COMMITTEE_GINDEX,
+            NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+            FINALIZED_ROOT_GINDEX,
+            FINALIZED_ROOT_PROOF_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >;
+
+        pub type LightClientFinalityUpdateDeneb = $crate::LightClientFinalityUpdateDeneb<
+            SYNC_COMMITTEE_SIZE,
+            FINALIZED_ROOT_GINDEX,
+            FINALIZED_ROOT_PROOF_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >;
+
+        pub type LightClientOptimisticUpdateDeneb = $crate::LightClientOptimisticUpdateDeneb<
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >;
+
+        pub type LightClientHeaderElectra =
+            $crate::LightClientHeaderElectra<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>;
+
+        pub type LightClientBootstrapElectra = $crate::LightClientBootstrapElectra<
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >;
+
+        pub type LightClientUpdateElectra = $crate::LightClientUpdateElectra<
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >;
+
+        pub type LightClientFinalityUpdateElectra = $crate::LightClientFinalityUpdateElectra<
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >;
+
+        pub type LightClientOptimisticUpdateElectra = $crate::LightClientOptimisticUpdateElectra<
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >;
+
+        pub type SyncCommitteeEquivocation =
+            $crate::SyncCommitteeEquivocation<LightClientUpdate>;
+
+        pub type SyncCommitteeEquivocationCapella =
+            $crate::SyncCommitteeEquivocation<LightClientUpdateCapella>;
+
+        pub type SyncCommitteeEquivocationDeneb =
+            $crate::SyncCommitteeEquivocation<LightClientUpdateDeneb>;
+
+        pub type SyncCommitteeEquivocationElectra =
+            $crate::SyncCommitteeEquivocation<LightClientUpdateElectra>;
+
+        pub type LightClientStore = $crate::LightClientStore<
+            SYNC_COMMITTEE_SIZE,
+            NEXT_SYNC_COMMITTEE_GINDEX,
+            NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+            FINALIZED_ROOT_GINDEX,
+            FINALIZED_ROOT_PROOF_SIZE,
+            SLOTS_PER_EPOCH,
+            EPOCHS_PER_SYNC_COMMITTEE_PERIOD,
+        >;
+
+        pub type LightClientStoreCapella = $crate::LightClientStore<
+            SYNC_COMMITTEE_SIZE,
+            NEXT_SYNC_COMMITTEE_GINDEX,
+            NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+            FINALIZED_ROOT_GINDEX,
+            FINALIZED_ROOT_PROOF_SIZE,
+            SLOTS_PER_EPOCH,
+            EPOCHS_PER_SYNC_COMMITTEE_PERIOD,
+            LightClientUpdateCapella,
+        >;
+
+        pub type LightClientStoreDeneb = $crate::LightClientStore<
+            SYNC_COMMITTEE_SIZE,
+            NEXT_SYNC_COMMITTEE_GINDEX,
+            NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+            FINALIZED_ROOT_GINDEX,
+            FINALIZED_ROOT_PROOF_SIZE,
+            SLOTS_PER_EPOCH,
+            EPOCHS_PER_SYNC_COMMITTEE_PERIOD,
+            LightClientUpdateDeneb,
+        >;
+
+        pub type LightClientStoreElectra = $crate::LightClientStore<
+            SYNC_COMMITTEE_SIZE,
+            NEXT_SYNC_COMMITTEE_GINDEX,
+            NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+            FINALIZED_ROOT_GINDEX,
+            FINALIZED_ROOT_PROOF_SIZE,
+            SLOTS_PER_EPOCH,
+            EPOCHS_PER_SYNC_COMMITTEE_PERIOD,
+            LightClientUpdateElectra,
+        >;
+
+        pub type LightClientStoreSnapshot = $crate::LightClientStoreSnapshot<
+            SYNC_COMMITTEE_SIZE,
+            NEXT_SYNC_COMMITTEE_GINDEX,
+            NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+            FINALIZED_ROOT_GINDEX,
+            FINALIZED_ROOT_PROOF_SIZE,
+        >;
+
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+        pub struct $preset;
+
+        impl $crate::spec::Spec for $preset {
+            const NAME: &'static str = PRESET_NAME;
+            const SYNC_COMMITTEE_SIZE: usize = SYNC_COMMITTEE_SIZE;
+            const CURRENT_SYNC_COMMITTEE_GINDEX: usize = CURRENT_SYNC_COMMITTEE_GINDEX;
+            const CURRENT_SYNC_COMMITTEE_PROOF_SIZE: usize = CURRENT_SYNC_COMMITTEE_PROOF_SIZE;
+            const NEXT_SYNC_COMMITTEE_GINDEX: usize = NEXT_SYNC_COMMITTEE_GINDEX;
+            const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize = NEXT_SYNC_COMMITTEE_PROOF_SIZE;
+            const FINALIZED_ROOT_GINDEX: usize = FINALIZED_ROOT_GINDEX;
+            const FINALIZED_ROOT_PROOF_SIZE: usize = FINALIZED_ROOT_PROOF_SIZE;
+            const BYTES_PER_LOGS_BLOOM: usize = BYTES_PER_LOGS_BLOOM;
+            const MAX_EXTRA_DATA_BYTES: usize = MAX_EXTRA_DATA_BYTES;
+            const SLOTS_PER_EPOCH: usize = SLOTS_PER_EPOCH;
+            const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize = EPOCHS_PER_SYNC_COMMITTEE_PERIOD;
+            const MAX_DEPOSITS: usize = MAX_DEPOSITS;
+
+            type SyncAggregate = SyncAggregate;
+            type SyncCommittee = SyncCommittee;
+            type ExecutionPayloadHeader = ExecutionPayloadHeader;
+            type LightClientHeader = LightClientHeader;
+            type LightClientUpdate = LightClientUpdate;
+            type LightClientUpdateCapella = LightClientUpdateCapella;
+            type LightClientBootstrap = LightClientBootstrap;
+            type LightClientFinalityUpdate = LightClientFinalityUpdate;
+            type LightClientOptimisticUpdate = LightClientOptimisticUpdate;
+            type LightClientOptimisticUpdateCapella = LightClientOptimisticUpdateCapella;
+            type ExecutionPayloadHeaderDeneb = ExecutionPayloadHeaderDeneb;
+            type LightClientHeaderDeneb = LightClientHeaderDeneb;
+            type LightClientBootstrapDeneb = LightClientBootstrapDeneb;
+            type LightClientUpdateDeneb = LightClientUpdateDeneb;
+            type LightClientFinalityUpdateDeneb = LightClientFinalityUpdateDeneb;
+            type LightClientOptimisticUpdateDeneb = LightClientOptimisticUpdateDeneb;
+            type LightClientHeaderElectra = LightClientHeaderElectra;
+            type LightClientBootstrapElectra = LightClientBootstrapElectra;
+            type LightClientUpdateElectra = LightClientUpdateElectra;
+            type LightClientFinalityUpdateElectra = LightClientFinalityUpdateElectra;
+            type LightClientOptimisticUpdateElectra = LightClientOptimisticUpdateElectra;
+            type SyncCommitteeEquivocation = SyncCommitteeEquivocation;
+            type LightClientStore = LightClientStore;
+            type LightClientStoreCapella = LightClientStoreCapella;
+            type LightClientStoreDeneb = LightClientStoreDeneb;
+            type LightClientStoreElectra = LightClientStoreElectra;
+            type LightClientStoreSnapshot = LightClientStoreSnapshot;
+        }
+    };
+}
@@ -1,35 +1,17 @@
-pub const SYNC_COMMITTEE_SIZE: usize = 32;
-pub const NEXT_SYNC_COMMITTEE_GINDEX: usize = 55;
-pub const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize = 5;
-pub const FINALIZED_ROOT_GINDEX: usize = 105;
-pub const FINALIZED_ROOT_PROOF_SIZE: usize = 6;
-pub const BYTES_PER_LOGS_BLOOM: usize = 256;
-pub const MAX_EXTRA_DATA_BYTES: usize = 32;
-
-pub type SyncAggregate = crate::SyncAggregate<SYNC_COMMITTEE_SIZE>;
-
-pub type SyncCommittee = crate::SyncCommittee<SYNC_COMMITTEE_SIZE>;
-
-pub type LightClientUpdate = crate::LightClientUpdate<
-    SYNC_COMMITTEE_SIZE,
-    NEXT_SYNC_COMMITTEE_GINDEX,
-    NEXT_SYNC_COMMITTEE_PROOF_SIZE,
-    FINALIZED_ROOT_GINDEX,
-    FINALIZED_ROOT_PROOF_SIZE,
->;
-pub type LightClientUpdateCapella = crate::LightClientUpdateCapella<
-    SYNC_COMMITTEE_SIZE,
-    NEXT_SYNC_COMMITTEE_GINDEX,
-    NEXT_SYNC_COMMITTEE_PROOF_SIZE,
-    FINALIZED_ROOT_GINDEX,
-    FINALIZED_ROOT_PROOF_SIZE,
-    BYTES_PER_LOGS_BLOOM,
-    MAX_EXTRA_DATA_BYTES,
->;
-
-pub type LightClientBootstrap = crate::LightClientBootstrap<
-    SYNC_COMMITTEE_SIZE,
-    NEXT_SYNC_COMMITTEE_PROOF_SIZE,
-    BYTES_PER_LOGS_BLOOM,
-    MAX_EXTRA_DATA_BYTES,
->;
+crate::declare_preset! {
+    /// The minimal preset as a `Spec`
+    Minimal = "minimal" {
+        SYNC_COMMITTEE_SIZE: 32,
+        CURRENT_SYNC_COMMITTEE_GINDEX: 54,
+        CURRENT_SYNC_COMMITTEE_PROOF_SIZE: 5,
+        NEXT_SYNC_COMMITTEE_GINDEX: 55,
+        NEXT_SYNC_COMMITTEE_PROOF_SIZE: 5,
+        FINALIZED_ROOT_GINDEX: 105,
+        FINALIZED_ROOT_PROOF_SIZE: 6,
+        BYTES_PER_LOGS_BLOOM: 256,
+        MAX_EXTRA_DATA_BYTES: 32,
+        SLOTS_PER_EPOCH: 8,
+        EPOCHS_PER_SYNC_COMMITTEE_PERIOD: 8,
+        MAX_DEPOSITS: 16,
+    }
+}
@@ -1 +1,74 @@
+mod macros;
+
+pub mod gnosis;
+pub mod mainnet;
 pub mod minimal;
+
+/// The preset a chain is run with, named as in the `PRESET_BASE` of its config
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PresetBase {
+    Mainnet,
+    Minimal,
+    Gnosis,
+}
+
+impl PresetBase {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Mainnet => mainnet::PRESET_NAME,
+            Self::Minimal => minimal::PRESET_NAME,
+            Self::Gnosis => gnosis::PRESET_NAME,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        [Self::Mainnet, Self::Minimal, Self::Gnosis]
+            .into_iter()
+            .find(|preset| preset.name() == name)
+    }
+}
+
+/// Depth in the merkle tree of the node at generalized index `gindex`, i.e. the length of its proof
+pub const fn gindex_depth(gindex: usize) -> usize {
+    gindex.ilog2() as usize
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::spec::Spec;
+
+    #[allow(dead_code)]
+    mod devnet {
+        crate::declare_preset! {
+            Devnet = "devnet" {
+                SYNC_COMMITTEE_SIZE: 16,
+                CURRENT_SYNC_COMMITTEE_GINDEX: 54,
+                CURRENT_SYNC_COMMITTEE_PROOF_SIZE: 5,
+                NEXT_SYNC_COMMITTEE_GINDEX: 55,
+                NEXT_SYNC_COMMITTEE_PROOF_SIZE: 5,
+                FINALIZED_ROOT_GINDEX: 105,
+                FINALIZED_ROOT_PROOF_SIZE: 6,
+                BYTES_PER_LOGS_BLOOM: 256,
+                MAX_EXTRA_DATA_BYTES: 32,
+                SLOTS_PER_EPOCH: 4,
+                EPOCHS_PER_SYNC_COMMITTEE_PERIOD: 2,
+                MAX_DEPOSITS: 16,
+            }
+        }
+    }
+
+    #[test]
+    fn test_declare_preset() {
+        assert_eq!(gindex_depth(1), 0);
+        assert_eq!(gindex_depth(105), 6);
+
+        assert_eq!(devnet::Devnet::NAME, "devnet");
+        assert_eq!(devnet::Devnet::UPDATE_TIMEOUT, 8);
+        assert_eq!(
+            devnet::SyncCommittee::default().pubkeys.len(),
+            devnet::SYNC_COMMITTEE_SIZE
+        );
+        assert_eq!(minimal::Minimal::NAME, PresetBase::Minimal.name());
+    }
+}
@@ -1,7 +1,8 @@
 use alloc::{vec, vec::Vec};
 use ssz_rs::prelude::*;
 
-use crate::primitives::{Domain, Root, Version};
+use crate::clock::compute_epoch_at_slot;
+use crate::primitives::{Domain, Epoch, Root, Slot, Version};
 
 #[derive(Default, Debug, SimpleSerialize)]
 pub struct SigningData {
@@ -57,3 +58,108 @@ impl ForkData {
         digest
     }
 }
+
+/// The fork versions of a network together with its `genesis_validators_root`.
+///
+/// Sync committee signatures are made with the fork of the slot before `signature_slot`, so this
+/// resolves the `ForkData` to verify an update with from its `signature_slot` alone.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct ForkSchedule {
+    /// `(activation_epoch, fork_version)` pairs sorted by activation epoch
+    forks: Vec<(Epoch, Version)>,
+    pub genesis_validators_root: Root,
+    /// Headers from before this epoch carry no execution payload
+    pub capella_fork_epoch: Epoch,
+    /// Execution payloads from before this epoch carry no blob gas
+    pub deneb_fork_epoch: Epoch,
+}
+
+impl ForkSchedule {
+    /// Create a schedule from `(activation_epoch, fork_version)` pairs in any order.
+    /// The versions do not name their fork, so the Capella and Deneb activation epochs are given separately.
+    pub fn new(
+        genesis_validators_root: Root,
+        forks: impl IntoIterator<Item = (Epoch, Version)>,
+        capella_fork_epoch: Epoch,
+        deneb_fork_epoch: Epoch,
+    ) -> Self {
+        let mut forks: Vec<_> = forks.into_iter().collect();
+        forks.sort_by_key(|(epoch, _)| *epoch);
+        Self {
+            forks,
+            genesis_validators_root,
+            capella_fork_epoch,
+            deneb_fork_epoch,
+        }
+    }
+
+    /// Scheduled forks sorted by activation epoch
+    pub fn forks(&self) -> &[(Epoch, Version)] {
+        &self.forks
+    }
+
+    /// Version of the latest fork activated at or before `epoch`.
+    /// Epochs before every scheduled fork resolve to the default version.
+    pub fn fork_version_at_epoch(&self, epoch: Epoch) -> Version {
+        self.forks
+            .iter()
+            .rev()
+            .find(|(activation_epoch, _)| *activation_epoch <= epoch)
+            .map(|(_, version)| *version)
+            .unwrap_or_default()
+    }
+
+    /// The `ForkData` a sync committee signature included at `signature_slot` is made with.
+    ///
+    /// See https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#validate_light_client_update
+    pub fn fork_data_at_signature_slot<const SLOTS_PER_EPOCH: usize>(
+        &self,
+        signature_slot: Slot,
+    ) -> ForkData {
+        let slot = signature_slot.max(1) - 1;
+        ForkData {
+            fork_version: self
+                .fork_version_at_epoch(compute_epoch_at_slot::<SLOTS_PER_EPOCH>(slot)),
+            genesis_validators_root: self.genesis_validators_root,
+        }
+    }
+
+    /// The sync committee signing domain for a signature included at `signature_slot`
+    pub fn sync_committee_domain<const SLOTS_PER_EPOCH: usize>(
+        &self,
+        signature_slot: Slot,
+    ) -> Result<Domain, ssz_rs::MerkleizationError> {
+        compute_domain(
+            DomainType::SyncCommittee,
+            &self.fork_data_at_signature_slot::<SLOTS_PER_EPOCH>(signature_slot),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fork_data_at_signature_slot() {
+        let altair = [1, 0, 0, 0];
+        let bellatrix = [2, 0, 0, 0];
+        let schedule = ForkSchedule::new(
+            Root::default(),
+            [(2, bellatrix), (0, altair)],
+            Epoch::MAX,
+            Epoch::MAX,
+        );
+
+        let version_at = |slot| schedule.fork_data_at_signature_slot::<8>(slot).fork_version;
+        assert_eq!(version_at(0), altair);
+        assert_eq!(version_at(16), altair);
+        // the first slot of the fork is still signed over the previous slot
+        assert_eq!(version_at(17), bellatrix);
+        assert_eq!(schedule.fork_version_at_epoch(u64::MAX), bellatrix);
+        assert_eq!(
+            ForkSchedule::default().fork_version_at_epoch(5),
+            Version::default()
+        );
+    }
+}
@@ -0,0 +1,289 @@
+//! Versioned SSZ encoding of the trusted state of a `LightClientStore`, so a light client can resume
+//! after a restart instead of syncing again from a checkpoint.
+use crate::{BeaconBlockHeader, LightClientStore, LightClientUpdate, SyncCommittee, Version};
+use alloc::{vec, vec::Vec};
+use ssz_rs::prelude::*;
+
+/// Layout version of `LightClientStoreSnapshot` written by this crate
+pub const SNAPSHOT_FORMAT_VERSION: u16 = 1;
+
+/// Rewrites the bytes of a snapshot into the layout of the following format version.
+pub type Migration = fn(&[u8]) -> Result<Vec<u8>, SnapshotError>;
+
+/// Migrations from every previous format version, where `MIGRATIONS[i]` upgrades version `i + 1` to `i + 2`.
+///
+/// When the layout of `LightClientStoreSnapshot` changes `SNAPSHOT_FORMAT_VERSION` is bumped and a
+/// migration from the previous layout is appended here.
+pub const MIGRATIONS: &[Migration] = &[];
+
+#[derive(Debug)]
+pub enum SnapshotError {
+    Serialize(ssz_rs::SerializeError),
+    Deserialize(ssz_rs::DeserializeError),
+    /// Too short to hold a format version
+    MissingFormatVersion,
+    /// Written by a newer version of this crate, or not a snapshot at all
+    UnsupportedFormatVersion(u16),
+}
+
+impl From<ssz_rs::SerializeError> for SnapshotError {
+    fn from(value: ssz_rs::SerializeError) -> Self {
+        Self::Serialize(value)
+    }
+}
+
+impl From<ssz_rs::DeserializeError> for SnapshotError {
+    fn from(value: ssz_rs::DeserializeError) -> Self {
+        Self::Deserialize(value)
+    }
+}
+
+/// The persisted form of a `LightClientStore`.
+///
+/// `format_version` is the first field so it can be read before the rest of the layout is known.
+/// `fork_version` tags the fork the store was last updated in.
+#[derive(Clone, Default, Debug, Eq, PartialEq, SimpleSerialize)]
+pub struct LightClientStoreSnapshot<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const NEXT_SYNC_COMMITTEE_GINDEX: usize,
+    const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize,
+    const FINALIZED_ROOT_GINDEX: usize,
+    const FINALIZED_ROOT_PROOF_SIZE: usize,
+> {
+    pub format_version: u16,
+    pub fork_version: Version,
+    pub finalized_header: BeaconBlockHeader,
+    pub current_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
+    pub next_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
+    /// Empty if there is no best valid update
+    pub best_valid_update: List<
+        LightClientUpdate<
+            SYNC_COMMITTEE_SIZE,
+            NEXT_SYNC_COMMITTEE_GINDEX,
+            NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+            FINALIZED_ROOT_GINDEX,
+            FINALIZED_ROOT_PROOF_SIZE,
+        >,
+        1,
+    >,
+    pub optimistic_header: BeaconBlockHeader,
+    pub previous_max_active_participants: u64,
+    pub current_max_active_participants: u64,
+    pub participation_window: u64,
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const NEXT_SYNC_COMMITTEE_GINDEX: usize,
+        const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize,
+        const FINALIZED_ROOT_GINDEX: usize,
+        const FINALIZED_ROOT_PROOF_SIZE: usize,
+    >
+    LightClientStoreSnapshot<
+        SYNC_COMMITTEE_SIZE,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+        FINALIZED_ROOT_GINDEX,
+        FINALIZED_ROOT_PROOF_SIZE,
+    >
+{
+    pub fn encode(&self) -> Result<Vec<u8>, SnapshotError> {
+        Ok(ssz_rs::serialize(self)?)
+    }
+
+    /// Decode a snapshot written by this or any earlier version of the crate
+    pub fn decode(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        Self::decode_with_migrations(bytes, MIGRATIONS)
+    }
+
+    /// Decode a snapshot, upgrading it one format version at a time with `migrations`,
+    /// where `migrations[i]` upgrades version `i + 1` to `i + 2`.
+    pub fn decode_with_migrations(
+        bytes: &[u8],
+        migrations: &[Migration],
+    ) -> Result<Self, SnapshotError> {
+        let bytes = migrate(bytes, migrations, SNAPSHOT_FORMAT_VERSION)?;
+        Ok(ssz_rs::deserialize(&bytes)?)
+    }
+}
+
+/// Upgrade the bytes of a snapshot to `target_version` one format version at a time with `migrations`,
+/// where `migrations[i]` upgrades version `i + 1` to `i + 2`.
+pub fn migrate(
+    bytes: &[u8],
+    migrations: &[Migration],
+    target_version: u16,
+) -> Result<Vec<u8>, SnapshotError> {
+    let mut format_version = read_format_version(bytes)?;
+    if format_version == 0 || format_version > target_version {
+        return Err(SnapshotError::UnsupportedFormatVersion(format_version));
+    }
+    let mut bytes = bytes.to_vec();
+    while format_version < target_version {
+        let migration = migrations
+            .get(format_version as usize - 1)
+            .ok_or(SnapshotError::UnsupportedFormatVersion(format_version))?;
+        bytes = migration(&bytes)?;
+        let migrated_version = read_format_version(&bytes)?;
+        if migrated_version != format_version + 1 {
+            return Err(SnapshotError::UnsupportedFormatVersion(migrated_version));
+        }
+        format_version = migrated_version;
+    }
+    Ok(bytes)
+}
+
+/// Read the `format_version` leading every snapshot
+pub fn read_format_version(bytes: &[u8]) -> Result<u16, SnapshotError> {
+    match bytes {
+        [low, high, ..] => Ok(u16::from_le_bytes([*low, *high])),
+        _ => Err(SnapshotError::MissingFormatVersion),
+    }
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const NEXT_SYNC_COMMITTEE_GINDEX: usize,
+        const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize,
+        const FINALIZED_ROOT_GINDEX: usize,
+        const FINALIZED_ROOT_PROOF_SIZE: usize,
+        const SLOTS_PER_EPOCH: usize,
+        const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize,
+    >
+    LightClientStore<
+        SYNC_COMMITTEE_SIZE,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+        FINALIZED_ROOT_GINDEX,
+        FINALIZED_ROOT_PROOF_SIZE,
+        SLOTS_PER_EPOCH,
+        EPOCHS_PER_SYNC_COMMITTEE_PERIOD,
+    >
+{
+    /// Snapshot the store, tagged with the `fork_version` it was last updated in
+    pub fn to_snapshot(
+        &self,
+        fork_version: Version,
+    ) -> LightClientStoreSnapshot<
+        SYNC_COMMITTEE_SIZE,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+        FINALIZED_ROOT_GINDEX,
+        FINALIZED_ROOT_PROOF_SIZE,
+    > {
+        LightClientStoreSnapshot {
+            format_version: SNAPSHOT_FORMAT_VERSION,
+            fork_version,
+            finalized_header: self.finalized_header.clone(),
+            current_sync_committee: self.current_sync_committee.clone(),
+            next_sync_committee: self.next_sync_committee.clone(),
+            // can unwrap as there is at most one update
+            best_valid_update: self
+                .best_valid_update
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap(),
+            optimistic_header: self.optimistic_header.clone(),
+            previous_max_active_participants: self.previous_max_active_participants as u64,
+            current_max_active_participants: self.current_max_active_participants as u64,
+            participation_window: self.participation_window,
+        }
+    }
+
+    /// Restore a store from a snapshot. The fork it was taken in is left to the caller to check.
+    pub fn from_snapshot(
+        snapshot: LightClientStoreSnapshot<
+            SYNC_COMMITTEE_SIZE,
+            NEXT_SYNC_COMMITTEE_GINDEX,
+            NEXT_SYNC_COMMITTEE_PROOF_SIZE,
+            FINALIZED_ROOT_GINDEX,
+            FINALIZED_ROOT_PROOF_SIZE,
+        >,
+    ) -> Self {
+        Self {
+            finalized_header: snapshot.finalized_header,
+            current_sync_committee: snapshot.current_sync_committee,
+            next_sync_committee: snapshot.next_sync_committee,
+            best_valid_update: snapshot.best_valid_update.first().cloned(),
+            optimistic_header: snapshot.optimistic_header,
+            previous_max_active_participants: snapshot.previous_max_active_participants as usize,
+            current_max_active_participants: snapshot.current_max_active_participants as usize,
+            participation_window: snapshot.participation_window,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::presets::minimal;
+
+    #[test]
+    fn test_snapshot_roundtrip() {
+        let mut store = minimal::LightClientStore::default();
+        store.finalized_header.slot = 64;
+        store.optimistic_header.slot = 70;
+        store.current_max_active_participants = 20;
+        let mut update = minimal::LightClientUpdate::default();
+        update.signature_slot = 71;
+        store.best_valid_update = Some(update);
+
+        let bytes = store.to_snapshot([1, 0, 0, 0]).encode().unwrap();
+        assert_eq!(
+            read_format_version(&bytes).unwrap(),
+            SNAPSHOT_FORMAT_VERSION
+        );
+        let snapshot = minimal::LightClientStoreSnapshot::decode(&bytes).unwrap();
+        assert_eq!(snapshot.fork_version, [1, 0, 0, 0]);
+        assert_eq!(minimal::LightClientStore::from_snapshot(snapshot), store);
+
+        let mut unknown = bytes.clone();
+        unknown[0] = 0xff;
+        assert!(matches!(
+            minimal::LightClientStoreSnapshot::decode(&unknown),
+            Err(SnapshotError::UnsupportedFormatVersion(0xff))
+        ));
+    }
+
+    #[test]
+    fn test_decode_with_migrations() {
+        let mut store = minimal::LightClientStore::default();
+        store.finalized_header.slot = 64;
+        store.participation_window = 3;
+        let snapshot = store.to_snapshot([0, 0, 0, 0]);
+        let bytes = snapshot.encode().unwrap();
+
+        // the current version decodes without running any migration
+        let fail: Migration = |_| Err(SnapshotError::MissingFormatVersion);
+        assert_eq!(
+            minimal::LightClientStoreSnapshot::decode_with_migrations(&bytes, &[fail]).unwrap(),
+            snapshot
+        );
+
+        // a hypothetical version 2 that tags snapshots of version 1 with the Altair fork version
+        let tag_fork_version: Migration = |bytes| {
+            let mut bytes = bytes.to_vec();
+            bytes[..2].copy_from_slice(&2u16.to_le_bytes());
+            bytes[2..6].copy_from_slice(&[1, 0, 0, 0]);
+            Ok(bytes)
+        };
+        let migrated = migrate(&bytes, &[tag_fork_version], 2).unwrap();
+        let upgraded: minimal::LightClientStoreSnapshot = ssz_rs::deserialize(&migrated).unwrap();
+        assert_eq!(upgraded.format_version, 2);
+        assert_eq!(upgraded.fork_version, [1, 0, 0, 0]);
+        assert_eq!(minimal::LightClientStore::from_snapshot(upgraded), store);
+
+        // a missing or misbehaving migration is reported with the version it failed at
+        assert!(matches!(
+            migrate(&bytes, &[], 2),
+            Err(SnapshotError::UnsupportedFormatVersion(1))
+        ));
+        let noop: Migration = |bytes| Ok(bytes.to_vec());
+        assert!(matches!(
+            migrate(&bytes, &[noop], 2),
+            Err(SnapshotError::UnsupportedFormatVersion(1))
+        ));
+    }
+}
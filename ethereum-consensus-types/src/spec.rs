@@ -0,0 +1,201 @@
+//! A single type parameter standing for all the preset values of a chain.
+use crate::light_client::{AnyLightClientStore, ForkBootstrap, ForkHeader, ForkUpdate};
+use crate::sync_committee::AnySyncCommittee;
+use crate::{BeaconBlockHeader, Slot};
+use core::fmt::Debug;
+use ssz_rs::prelude::*;
+
+/// The preset values of a chain, and every container instantiated with them.
+///
+/// Containers stay generic over their individual const parameters, as stable Rust does not accept
+/// an associated constant such as `S::SYNC_COMMITTEE_SIZE` as a const generic argument. Instead each
+/// preset binds the concrete containers as associated types, so code written once against
+/// `S: Spec` uses e.g. `S::LightClientUpdate` and works for minimal, mainnet or a custom chain.
+///
+/// The associated types must be instantiated with the associated constants of the same preset. They are
+/// bound by the verification traits, so generic code can read their fields through the trait
+/// accessors, verify them and drive the store with them.
+pub trait Spec: 'static + Clone + Copy + Debug + Default + PartialEq + Eq + Send + Sync {
+    /// Name of the preset, as the `PRESET_BASE` of a config using it
+    const NAME: &'static str;
+
+    const SYNC_COMMITTEE_SIZE: usize;
+    const CURRENT_SYNC_COMMITTEE_GINDEX: usize;
+    const CURRENT_SYNC_COMMITTEE_PROOF_SIZE: usize;
+    const NEXT_SYNC_COMMITTEE_GINDEX: usize;
+    const NEXT_SYNC_COMMITTEE_PROOF_SIZE: usize;
+    const FINALIZED_ROOT_GINDEX: usize;
+    const FINALIZED_ROOT_PROOF_SIZE: usize;
+    const BYTES_PER_LOGS_BLOOM: usize;
+    const MAX_EXTRA_DATA_BYTES: usize;
+    const SLOTS_PER_EPOCH: usize;
+    const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize;
+    /// Maximum number of deposits in a block, which bounds balance top-ups in the weak subjectivity period
+    const MAX_DEPOSITS: usize;
+
+    /// Slots in a sync committee period, after which a pending update is force applied
+    const UPDATE_TIMEOUT: Slot =
+        Self::SLOTS_PER_EPOCH as u64 * Self::EPOCHS_PER_SYNC_COMMITTEE_PERIOD as u64;
+
+    type SyncAggregate: SpecContainer;
+    type SyncCommittee: SpecContainer + AnySyncCommittee<SyncAggregate = Self::SyncAggregate>;
+
+    type ExecutionPayloadHeader: SpecContainer;
+    type LightClientHeader: SpecContainer + ForkHeader + Into<BeaconBlockHeader>;
+    type LightClientUpdate: SpecContainer
+        + ForkUpdate<SyncCommittee = Self::SyncCommittee, Header = BeaconBlockHeader>;
+    type LightClientUpdateCapella: SpecContainer
+        + ForkUpdate<SyncCommittee = Self::SyncCommittee, Header = Self::LightClientHeader>;
+    type LightClientBootstrap: SpecContainer
+        + ForkBootstrap<SyncCommittee = Self::SyncCommittee, Header = Self::LightClientHeader>;
+    type LightClientFinalityUpdate: SpecContainer
+        + ForkUpdate<SyncCommittee = Self::SyncCommittee, Header = Self::LightClientHeader>;
+    type LightClientOptimisticUpdate: SpecContainer
+        + ForkUpdate<SyncCommittee = Self::SyncCommittee, Header = BeaconBlockHeader>;
+    type LightClientOptimisticUpdateCapella: SpecContainer
+        + ForkUpdate<SyncCommittee = Self::SyncCommittee, Header = Self::LightClientHeader>;
+
+    type ExecutionPayloadHeaderDeneb: SpecContainer;
+    type LightClientHeaderDeneb: SpecContainer + ForkHeader;
+    type LightClientBootstrapDeneb: SpecContainer
+        + ForkBootstrap<SyncCommittee = Self::SyncCommittee, Header = Self::LightClientHeaderDeneb>;
+    type LightClientUpdateDeneb: SpecContainer
+        + ForkUpdate<SyncCommittee = Self::SyncCommittee, Header = Self::LightClientHeaderDeneb>;
+    type LightClientFinalityUpdateDeneb: SpecContainer
+        + ForkUpdate<SyncCommittee = Self::SyncCommittee, Header = Self::LightClientHeaderDeneb>;
+    type LightClientOptimisticUpdateDeneb: SpecContainer
+        + ForkUpdate<SyncCommittee = Self::SyncCommittee, Header = Self::LightClientHeaderDeneb>;
+
+    type LightClientHeaderElectra: SpecContainer + ForkHeader;
+    type LightClientBootstrapElectra: SpecContainer
+        + ForkBootstrap<SyncCommittee = Self::SyncCommittee, Header = Self::LightClientHeaderElectra>;
+    type LightClientUpdateElectra: SpecContainer
+        + ForkUpdate<SyncCommittee = Self::SyncCommittee, Header = Self::LightClientHeaderElectra>;
+    type LightClientFinalityUpdateElectra: SpecContainer
+        + ForkUpdate<SyncCommittee = Self::SyncCommittee, Header = Self::LightClientHeaderElectra>;
+    type LightClientOptimisticUpdateElectra: SpecContainer
+        + ForkUpdate<SyncCommittee = Self::SyncCommittee, Header = Self::LightClientHeaderElectra>;
+
+    type SyncCommitteeEquivocation: SpecContainer;
+    type LightClientStore: AnyLightClientStore<
+        SyncCommittee = Self::SyncCommittee,
+        Update = Self::LightClientUpdate,
+    >;
+    /// Stores of the later forks keep their headers together with the execution payload header
+    type LightClientStoreCapella: AnyLightClientStore<
+        SyncCommittee = Self::SyncCommittee,
+        Update = Self::LightClientUpdateCapella,
+    >;
+    type LightClientStoreDeneb: AnyLightClientStore<
+        SyncCommittee = Self::SyncCommittee,
+        Update = Self::LightClientUpdateDeneb,
+    >;
+    type LightClientStoreElectra: AnyLightClientStore<
+        SyncCommittee = Self::SyncCommittee,
+        Update = Self::LightClientUpdateElectra,
+    >;
+    type LightClientStoreSnapshot: SpecContainer;
+}
+
+/// Bounds shared by every SSZ container bound in a `Spec`
+pub trait SpecContainer: SimpleSerialize + Clone + Debug + Default + PartialEq {}
+
+impl<T: SimpleSerialize + Clone + Debug + Default + PartialEq> SpecContainer for T {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::presets::{mainnet::Mainnet, minimal, minimal::Minimal};
+    use crate::test_utils::{merkle_tree, TestCommittee};
+    use crate::{Epoch, ForkSchedule, Root, VerificationError};
+
+    // written once for any preset
+    fn sync_from_bootstrap<S: Spec>(
+        trusted_block_root: Root,
+        bootstrap: &S::LightClientBootstrap,
+        update: &S::LightClientUpdate,
+        fork_schedule: &ForkSchedule,
+    ) -> Result<S::LightClientStore, VerificationError> {
+        let mut store =
+            S::LightClientStore::initialize(trusted_block_root, bootstrap, fork_schedule)?;
+        let current_slot = update.signature_slot();
+        store.process_light_client_update(update, current_slot, fork_schedule)?;
+        Ok(store)
+    }
+
+    fn initialize_capella_store<S: Spec>(
+        trusted_block_root: Root,
+        bootstrap: &S::LightClientBootstrap,
+        fork_schedule: &ForkSchedule,
+    ) -> Result<S::LightClientStoreCapella, VerificationError> {
+        S::LightClientStoreCapella::initialize(trusted_block_root, bootstrap, fork_schedule)
+    }
+
+    #[test]
+    fn test_spec_is_generic_over_presets() {
+        assert_eq!(Minimal::UPDATE_TIMEOUT, 64);
+        assert_eq!(Mainnet::UPDATE_TIMEOUT, 8192);
+
+        // no execution payloads, so headers need no execution branch
+        let fork_schedule = ForkSchedule {
+            capella_fork_epoch: Epoch::MAX,
+            deneb_fork_epoch: Epoch::MAX,
+            ..ForkSchedule::default()
+        };
+        let committee = TestCommittee::<{ minimal::SYNC_COMMITTEE_SIZE }>::new(1);
+        let committee_root = committee.committee.clone().hash_tree_root().unwrap();
+
+        let (state_root, branches) =
+            merkle_tree(&[(minimal::CURRENT_SYNC_COMMITTEE_GINDEX, committee_root)]);
+        let mut bootstrap = minimal::LightClientBootstrap::default();
+        bootstrap.header.beacon = BeaconBlockHeader {
+            slot: 8,
+            state_root,
+            ..Default::default()
+        };
+        bootstrap.current_sync_committee = committee.committee.clone();
+        bootstrap.current_sync_committee_branch = Vector::try_from(branches[0].clone()).unwrap();
+        let trusted_block_root = bootstrap.header.beacon.clone().hash_tree_root().unwrap();
+
+        // the committee signs an update proving itself as the next one
+        let (state_root, branches) =
+            merkle_tree(&[(minimal::NEXT_SYNC_COMMITTEE_GINDEX, committee_root)]);
+        let mut update = minimal::LightClientUpdate::default();
+        update.attested_header = BeaconBlockHeader {
+            slot: 16,
+            state_root,
+            ..Default::default()
+        };
+        update.next_sync_committee = committee.committee.clone();
+        update.next_sync_committee_branch = Vector::try_from(branches[0].clone()).unwrap();
+        update.signature_slot = 17;
+        update.sync_aggregate = committee.sign::<{ minimal::SLOTS_PER_EPOCH }>(
+            &update.attested_header,
+            17,
+            &fork_schedule,
+        );
+
+        let store =
+            sync_from_bootstrap::<Minimal>(trusted_block_root, &bootstrap, &update, &fork_schedule)
+                .unwrap();
+        assert_eq!(store.optimistic_header(), &update.attested_header);
+        assert_eq!(store.finalized_header(), &bootstrap.header.beacon);
+
+        // a Capella store keeps the whole bootstrap header
+        let store =
+            initialize_capella_store::<Minimal>(trusted_block_root, &bootstrap, &fork_schedule)
+                .unwrap();
+        assert_eq!(store.finalized_header(), &bootstrap.header);
+
+        // the committee did not sign a different header
+        update.attested_header.proposer_index = 1;
+        assert!(matches!(
+            sync_from_bootstrap::<Minimal>(trusted_block_root, &bootstrap, &update, &fork_schedule),
+            Err(VerificationError::SignatureMismatch { .. })
+        ));
+        assert!(matches!(
+            sync_from_bootstrap::<Minimal>(Root::default(), &bootstrap, &update, &fork_schedule),
+            Err(VerificationError::UntrustedBlockRoot { .. })
+        ));
+    }
+}
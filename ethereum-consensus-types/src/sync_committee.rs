@@ -1,6 +1,7 @@
 use crate::bls::{BlsPublicKey, BlsSignature};
-use crate::VerificationError;
+use crate::{Root, VerificationError};
 use alloc::{vec, vec::Vec};
+use core::fmt::Debug;
 use ssz_rs::prelude::*;
 
 /// A sync aggregate is an aggregate signature from a subset of members of a sync
@@ -13,10 +14,14 @@ pub struct SyncAggregate<const SYNC_COMMITTEE_SIZE: usize> {
 }
 
 impl<const SYNC_COMMITTEE_SIZE: usize> SyncAggregate<SYNC_COMMITTEE_SIZE> {
+    /// Number of sync committee members that contributed to the aggregate signature
+    pub fn num_participants(&self) -> usize {
+        self.sync_committee_bits.iter().filter(|b| **b).count()
+    }
+
     /// Verify that enough sync committee members have contributed their signature to the sync aggregate
     pub fn verify_participation(&self) -> Result<(), VerificationError> {
-        let participation = self.sync_committee_bits.iter().filter(|b| **b).count();
-        if participation * 3 >= SYNC_COMMITTEE_SIZE * 2 {
+        if self.num_participants() * 3 >= SYNC_COMMITTEE_SIZE * 2 {
             Ok(())
         } else {
             Err(VerificationError::InsufficientParticipation)
@@ -57,6 +62,49 @@ impl<const SYNC_COMMITTEE_SIZE: usize> SyncCommittee<SYNC_COMMITTEE_SIZE> {
     }
 }
 
+/// A sync committee of any size together with the aggregates it signs, so code verifying
+/// sync committee signatures is written once for every preset.
+pub trait AnySyncCommittee: SimpleSerialize + Clone + Debug + Default + PartialEq {
+    type SyncAggregate: SimpleSerialize + Clone + Debug + Default + PartialEq;
+
+    /// Number of members, i.e. `SYNC_COMMITTEE_SIZE`
+    const SIZE: usize;
+
+    fn num_participants(sync_aggregate: &Self::SyncAggregate) -> usize;
+
+    /// Verify that `sync_aggregate` holds a signature over `signing_root` from the participating members
+    fn verify_signature(
+        &self,
+        sync_aggregate: &Self::SyncAggregate,
+        signing_root: Root,
+    ) -> Result<(), VerificationError>;
+}
+
+impl<const SYNC_COMMITTEE_SIZE: usize> AnySyncCommittee for SyncCommittee<SYNC_COMMITTEE_SIZE> {
+    type SyncAggregate = SyncAggregate<SYNC_COMMITTEE_SIZE>;
+
+    const SIZE: usize = SYNC_COMMITTEE_SIZE;
+
+    fn num_participants(sync_aggregate: &Self::SyncAggregate) -> usize {
+        sync_aggregate.num_participants()
+    }
+
+    /// This is done by aggregating the committee public keys according to the sync committee bits.
+    fn verify_signature(
+        &self,
+        sync_aggregate: &Self::SyncAggregate,
+        signing_root: Root,
+    ) -> Result<(), VerificationError> {
+        let aggregate_pubkey = self
+            .aggregate_pubkey(&sync_aggregate.sync_committee_bits)
+            .ok_or(VerificationError::NoSigners)?;
+        Ok(aggregate_pubkey.verify_signature(
+            signing_root.as_ref(),
+            &sync_aggregate.sync_committee_signature,
+        )?)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
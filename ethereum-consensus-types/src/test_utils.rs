@@ -0,0 +1,119 @@
+//! Fixtures shared by the unit tests
+
+use crate::bls::{BlsPublicKey, BlsSignature, DST};
+use crate::primitives::{ByteVector, Bytes32, Root, Slot};
+use crate::signing::{compute_signing_root, ForkSchedule};
+use crate::{BeaconBlockHeader, SyncAggregate, SyncCommittee};
+use alloc::{collections::BTreeMap, vec::Vec};
+use blst::min_pk::{AggregateSignature, SecretKey};
+use sha2::{Digest, Sha256};
+use ssz_rs::prelude::*;
+
+fn hash(left: Node, right: Node) -> Node {
+    let mut hasher = Sha256::new();
+    hasher.update(left.as_ref());
+    hasher.update(right.as_ref());
+    Node::try_from(hasher.finalize().as_slice()).unwrap()
+}
+
+/// Root of the tree with `leaves` at their generalized indices and zero nodes everywhere else,
+/// together with the branch proving each leaf
+pub(crate) fn merkle_tree(leaves: &[(usize, Node)]) -> (Root, Vec<Vec<Node>>) {
+    let mut nodes: BTreeMap<usize, Node> = leaves.iter().copied().collect();
+    let max_gindex = leaves.iter().map(|(gindex, _)| *gindex).max().unwrap_or(1);
+    for gindex in (2..=max_gindex).rev() {
+        if nodes.contains_key(&gindex) && !nodes.contains_key(&(gindex / 2)) {
+            let left = nodes.get(&(gindex & !1)).copied().unwrap_or_default();
+            let right = nodes.get(&(gindex | 1)).copied().unwrap_or_default();
+            nodes.insert(gindex / 2, hash(left, right));
+        }
+    }
+    let branches = leaves
+        .iter()
+        .map(|(gindex, _)| {
+            let mut branch = Vec::new();
+            let mut gindex = *gindex;
+            while gindex > 1 {
+                branch.push(nodes.get(&(gindex ^ 1)).copied().unwrap_or_default());
+                gindex /= 2;
+            }
+            branch
+        })
+        .collect();
+    (nodes.get(&1).copied().unwrap_or_default(), branches)
+}
+
+/// A branch of `Bytes32` as carried by light client headers
+pub(crate) fn bytes32_branch(branch: &[Node]) -> Vec<Bytes32> {
+    branch
+        .iter()
+        .map(|node| ByteVector(node.as_ref().to_vec().try_into().unwrap()))
+        .collect()
+}
+
+/// A sync committee of `N` members together with their secret keys
+pub(crate) struct TestCommittee<const N: usize> {
+    secret_keys: Vec<SecretKey>,
+    pub committee: SyncCommittee<N>,
+}
+
+impl<const N: usize> TestCommittee<N> {
+    /// Derive the members from `seed`, so committees built from different seeds share no members
+    pub fn new(seed: u8) -> Self {
+        let secret_keys: Vec<_> = (0..N)
+            .map(|i| {
+                let mut ikm = [seed; 32];
+                ikm[0] = i as u8;
+                SecretKey::key_gen(&ikm, &[]).unwrap()
+            })
+            .collect();
+        let pubkeys: Vec<_> = secret_keys
+            .iter()
+            .map(|secret_key| BlsPublicKey::from_bytes(&secret_key.sk_to_pk().to_bytes()).unwrap())
+            .collect();
+        let aggregate_pubkey = pubkeys
+            .iter()
+            .cloned()
+            .reduce(|agg, key| agg.aggregate(key).unwrap())
+            .unwrap();
+        Self {
+            secret_keys,
+            committee: SyncCommittee {
+                pubkeys: Vector::try_from(pubkeys).unwrap(),
+                aggregate_pubkey,
+            },
+        }
+    }
+
+    /// A sync aggregate of every member signing `attested_header` in a block at `signature_slot`
+    pub fn sign<const SLOTS_PER_EPOCH: usize>(
+        &self,
+        attested_header: &BeaconBlockHeader,
+        signature_slot: Slot,
+        fork_schedule: &ForkSchedule,
+    ) -> SyncAggregate<N> {
+        let domain = fork_schedule
+            .sync_committee_domain::<SLOTS_PER_EPOCH>(signature_slot)
+            .unwrap();
+        let signing_root =
+            compute_signing_root(attested_header.clone().hash_tree_root().unwrap(), domain)
+                .unwrap();
+        let signatures: Vec<_> = self
+            .secret_keys
+            .iter()
+            .map(|secret_key| secret_key.sign(signing_root.as_ref(), DST, &[]))
+            .collect();
+        let signature =
+            AggregateSignature::aggregate(&signatures.iter().collect::<Vec<_>>(), false)
+                .unwrap()
+                .to_signature();
+        let mut sync_committee_bits = Bitvector::default();
+        for i in 0..N {
+            sync_committee_bits.set(i, true);
+        }
+        SyncAggregate {
+            sync_committee_bits,
+            sync_committee_signature: BlsSignature::from_bytes(&signature.to_bytes()).unwrap(),
+        }
+    }
+}
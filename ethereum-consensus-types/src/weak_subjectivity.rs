@@ -0,0 +1,173 @@
+//! Weak subjectivity period of a validator set, bounding how old a trusted checkpoint may be.
+//!
+//! See https://github.com/ethereum/consensus-specs/blob/dev/specs/phase0/weak-subjectivity.md
+use crate::clock::compute_epoch_at_slot;
+use crate::{ChainConfig, Epoch, Gwei, Slot, VerificationError};
+
+pub const SAFETY_DECAY: u64 = 10;
+pub const ETH_TO_GWEI: Gwei = 1_000_000_000;
+pub const MAX_EFFECTIVE_BALANCE: Gwei = 32 * ETH_TO_GWEI;
+
+/// Number of validators that may enter or exit the active set per epoch.
+/// A zero `churn_limit_quotient` leaves only `min_per_epoch_churn_limit`.
+pub fn compute_validator_churn_limit(
+    active_validator_count: u64,
+    min_per_epoch_churn_limit: u64,
+    churn_limit_quotient: u64,
+) -> u64 {
+    min_per_epoch_churn_limit.max(
+        active_validator_count
+            .checked_div(churn_limit_quotient)
+            .unwrap_or(0),
+    )
+}
+
+/// Number of epochs a finalized checkpoint of a validator set with `active_validator_count` validators
+/// and `total_active_balance` can be safely trusted for on a chain run with `config`.
+///
+/// This is the phase0 formula, where churn is bounded by the number of validators. From Electra on
+/// churn is bounded by balance instead, which this does not account for.
+///
+/// Returns `None` if the period depends on validator churn but `config` allows none, as it is then unbounded.
+pub fn compute_weak_subjectivity_period<const SLOTS_PER_EPOCH: usize, const MAX_DEPOSITS: usize>(
+    config: &ChainConfig,
+    active_validator_count: u64,
+    total_active_balance: Gwei,
+) -> Option<Epoch> {
+    let mut ws_period = config.min_validator_withdrawability_delay;
+    let n = active_validator_count;
+    if n == 0 {
+        return Some(ws_period);
+    }
+    let t = total_active_balance / n / ETH_TO_GWEI;
+    let max_t = MAX_EFFECTIVE_BALANCE / ETH_TO_GWEI;
+    let delta = compute_validator_churn_limit(
+        n,
+        config.min_per_epoch_churn_limit,
+        config.churn_limit_quotient,
+    );
+    let max_delta = (MAX_DEPOSITS * SLOTS_PER_EPOCH) as u64;
+    let d = SAFETY_DECAY;
+
+    if max_t * (200 + 3 * d) < t * (200 + 12 * d) {
+        if delta == 0 {
+            return None;
+        }
+        let epochs_for_validator_set_churn =
+            n * (t * (200 + 12 * d) - max_t * (200 + 3 * d)) / (600 * delta * (2 * t + max_t));
+        let epochs_for_balance_top_ups = n * (200 + 3 * d) / (600 * max_delta);
+        ws_period += epochs_for_validator_set_churn.max(epochs_for_balance_top_ups);
+    } else {
+        ws_period += 3 * n * d * t / (200 * max_delta * (max_t - t));
+    }
+    Some(ws_period)
+}
+
+/// Whether a checkpoint at `checkpoint_slot` can still be trusted at `current_slot`
+pub fn is_within_weak_subjectivity_period<const SLOTS_PER_EPOCH: usize>(
+    checkpoint_slot: Slot,
+    current_slot: Slot,
+    ws_period: Epoch,
+) -> bool {
+    let checkpoint_epoch = compute_epoch_at_slot::<SLOTS_PER_EPOCH>(checkpoint_slot);
+    let current_epoch = compute_epoch_at_slot::<SLOTS_PER_EPOCH>(current_slot);
+    current_epoch <= checkpoint_epoch.saturating_add(ws_period)
+}
+
+/// Reject a checkpoint at `checkpoint_slot` that is too old to be trusted at `current_slot`
+pub fn verify_weak_subjectivity<const SLOTS_PER_EPOCH: usize>(
+    checkpoint_slot: Slot,
+    current_slot: Slot,
+    ws_period: Epoch,
+) -> Result<(), VerificationError> {
+    if is_within_weak_subjectivity_period::<SLOTS_PER_EPOCH>(
+        checkpoint_slot,
+        current_slot,
+        ws_period,
+    ) {
+        Ok(())
+    } else {
+        Err(VerificationError::OutsideWeakSubjectivityPeriod {
+            checkpoint_slot,
+            current_slot,
+            ws_period,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::networks::Network;
+    use crate::presets::mainnet;
+
+    #[test]
+    fn test_compute_weak_subjectivity_period() {
+        let config = Network::Mainnet.chain_config();
+        let compute = |config: &ChainConfig, validators, average_balance| {
+            compute_weak_subjectivity_period::<
+                { mainnet::SLOTS_PER_EPOCH },
+                { mainnet::MAX_DEPOSITS },
+            >(
+                config,
+                validators,
+                validators * average_balance * ETH_TO_GWEI,
+            )
+        };
+
+        // table from the spec, for mainnet parameters
+        let cases = [
+            (32768, 28, 504),
+            (65536, 28, 752),
+            (131072, 28, 1248),
+            (262144, 28, 2241),
+            (524288, 28, 2241),
+            (1048576, 28, 2241),
+            (32768, 32, 665),
+            (65536, 32, 1075),
+            (131072, 32, 1894),
+            (262144, 32, 3532),
+            (524288, 32, 3532),
+            (1048576, 32, 3532),
+        ];
+        for (validators, average_balance, ws_period) in cases {
+            assert_eq!(
+                compute(&config, validators, average_balance),
+                Some(ws_period)
+            );
+        }
+
+        // without any churn the validator set never changes
+        let frozen = ChainConfig {
+            min_per_epoch_churn_limit: 0,
+            churn_limit_quotient: 0,
+            ..config.clone()
+        };
+        assert_eq!(compute(&frozen, 32768, 32), None);
+        // with low balances the period is bounded by top-ups alone
+        assert_eq!(compute(&frozen, 32768, 20), compute(&config, 32768, 20));
+    }
+
+    #[test]
+    fn test_is_within_weak_subjectivity_period() {
+        assert!(is_within_weak_subjectivity_period::<32>(320, 32 * 266, 256));
+        assert!(!is_within_weak_subjectivity_period::<32>(
+            320,
+            32 * 267,
+            256
+        ));
+    }
+
+    #[test]
+    fn test_outside_weak_subjectivity_period() {
+        assert!(verify_weak_subjectivity::<8>(8, 23, 1).is_ok());
+        assert!(matches!(
+            verify_weak_subjectivity::<8>(8, 24, 1),
+            Err(VerificationError::OutsideWeakSubjectivityPeriod {
+                checkpoint_slot: 8,
+                current_slot: 24,
+                ws_period: 1
+            })
+        ));
+    }
+}
@@ -3,6 +3,7 @@
 use ethereum_consensus::crypto::{PublicKey, Signature};
 use ethereum_consensus::ssz;
 use ethereum_consensus_types::bls::BlsSignature;
+use ethereum_consensus_types::presets::{mainnet, minimal};
 use ethereum_consensus_types::{
     BeaconBlockHeader, LightClientUpdate, SyncAggregate, SyncCommittee,
 };
@@ -58,6 +59,252 @@ fn test_light_client_update(
     ssz_static_test_no_yaml::<LightClientUpdate<32, 23, 5, 41, 6>>(&case);
 }
 
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_optimistic_update(
+    #[files(
+        "../consensus-spec-tests/tests/minimal/altair/ssz_static/LightClientOptimisticUpdate/**/case_*/"
+    )]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<minimal::LightClientOptimisticUpdate>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_optimistic_update_capella(
+    #[files(
+        "../consensus-spec-tests/tests/minimal/capella/ssz_static/LightClientOptimisticUpdate/**/case_*/"
+    )]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<minimal::LightClientOptimisticUpdateCapella>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_update_capella(
+    #[files(
+        "../consensus-spec-tests/tests/minimal/capella/ssz_static/LightClientUpdate/**/case_*/"
+    )]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<minimal::LightClientUpdateCapella>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_bootstrap_capella(
+    #[files(
+        "../consensus-spec-tests/tests/minimal/capella/ssz_static/LightClientBootstrap/**/case_*/"
+    )]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<minimal::LightClientBootstrap>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_finality_update_capella(
+    #[files("../consensus-spec-tests/tests/minimal/capella/ssz_static/LightClientFinalityUpdate/**/case_*/")]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<minimal::LightClientFinalityUpdate>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_header_deneb(
+    #[files("../consensus-spec-tests/tests/minimal/deneb/ssz_static/LightClientHeader/**/case_*/")]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<minimal::LightClientHeaderDeneb>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_bootstrap_deneb(
+    #[files("../consensus-spec-tests/tests/minimal/deneb/ssz_static/LightClientBootstrap/**/case_*/")]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<minimal::LightClientBootstrapDeneb>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_update_deneb(
+    #[files("../consensus-spec-tests/tests/minimal/deneb/ssz_static/LightClientUpdate/**/case_*/")]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<minimal::LightClientUpdateDeneb>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_finality_update_deneb(
+    #[files("../consensus-spec-tests/tests/minimal/deneb/ssz_static/LightClientFinalityUpdate/**/case_*/")]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<minimal::LightClientFinalityUpdateDeneb>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_optimistic_update_deneb(
+    #[files("../consensus-spec-tests/tests/minimal/deneb/ssz_static/LightClientOptimisticUpdate/**/case_*/")]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<minimal::LightClientOptimisticUpdateDeneb>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_header_electra(
+    #[files("../consensus-spec-tests/tests/minimal/electra/ssz_static/LightClientHeader/**/case_*/")]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<minimal::LightClientHeaderElectra>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_bootstrap_electra(
+    #[files("../consensus-spec-tests/tests/minimal/electra/ssz_static/LightClientBootstrap/**/case_*/")]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<minimal::LightClientBootstrapElectra>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_update_electra(
+    #[files("../consensus-spec-tests/tests/minimal/electra/ssz_static/LightClientUpdate/**/case_*/")]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<minimal::LightClientUpdateElectra>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_finality_update_electra(
+    #[files("../consensus-spec-tests/tests/minimal/electra/ssz_static/LightClientFinalityUpdate/**/case_*/")]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<minimal::LightClientFinalityUpdateElectra>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_optimistic_update_electra(
+    #[files("../consensus-spec-tests/tests/minimal/electra/ssz_static/LightClientOptimisticUpdate/**/case_*/")]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<minimal::LightClientOptimisticUpdateElectra>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_sync_aggregate_mainnet(
+    #[files("../consensus-spec-tests/tests/mainnet/altair/ssz_static/SyncAggregate/**/case_*/")]
+    case: PathBuf,
+) {
+    ssz_static_test_with_yaml::<mainnet::SyncAggregate>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_sync_committee_mainnet(
+    #[files("../consensus-spec-tests/tests/mainnet/altair/ssz_static/SyncCommittee/**/case_*/")]
+    case: PathBuf,
+) {
+    ssz_static_test_with_yaml::<mainnet::SyncCommittee>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_update_mainnet(
+    #[files("../consensus-spec-tests/tests/mainnet/altair/ssz_static/LightClientUpdate/**/case_*/")]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<mainnet::LightClientUpdate>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_optimistic_update_capella_mainnet(
+    #[files("../consensus-spec-tests/tests/mainnet/capella/ssz_static/LightClientOptimisticUpdate/**/case_*/")]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<mainnet::LightClientOptimisticUpdateCapella>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_update_capella_mainnet(
+    #[files(
+        "../consensus-spec-tests/tests/mainnet/capella/ssz_static/LightClientUpdate/**/case_*/"
+    )]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<mainnet::LightClientUpdateCapella>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_bootstrap_capella_mainnet(
+    #[files(
+        "../consensus-spec-tests/tests/mainnet/capella/ssz_static/LightClientBootstrap/**/case_*/"
+    )]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<mainnet::LightClientBootstrap>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_finality_update_capella_mainnet(
+    #[files("../consensus-spec-tests/tests/mainnet/capella/ssz_static/LightClientFinalityUpdate/**/case_*/")]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<mainnet::LightClientFinalityUpdate>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_bootstrap_deneb_mainnet(
+    #[files("../consensus-spec-tests/tests/mainnet/deneb/ssz_static/LightClientBootstrap/**/case_*/")]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<mainnet::LightClientBootstrapDeneb>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_update_deneb_mainnet(
+    #[files("../consensus-spec-tests/tests/mainnet/deneb/ssz_static/LightClientUpdate/**/case_*/")]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<mainnet::LightClientUpdateDeneb>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_finality_update_deneb_mainnet(
+    #[files("../consensus-spec-tests/tests/mainnet/deneb/ssz_static/LightClientFinalityUpdate/**/case_*/")]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<mainnet::LightClientFinalityUpdateDeneb>(&case);
+}
+
+#[rstest]
+#[cfg(feature = "serde")]
+fn test_light_client_update_electra_mainnet(
+    #[files("../consensus-spec-tests/tests/mainnet/electra/ssz_static/LightClientUpdate/**/case_*/")]
+    case: PathBuf,
+) {
+    ssz_static_test_no_yaml::<mainnet::LightClientUpdateElectra>(&case);
+}
+
 #[allow(clippy::ptr_arg)]
 /// This is a test for when the container does not support deserialization from YAML
 /// It deserialized from SSZ bytes and checks the Merkle root matches the one defined by the test